@@ -0,0 +1,72 @@
+//! Automatic Persisted Queries (APQ) detection.
+//!
+//! APQ lets a client send a SHA-256 hash of a query instead of its full text;
+//! if the server hasn't seen that hash before it replies
+//! `PersistedQueryNotFound`, and a well-behaved client resends the hash
+//! alongside the full query text to register it. Some deployments harden
+//! this into an allowlist: only hashes registered out-of-band are ever
+//! accepted, so self-registration is permanently rejected even after
+//! resending the query text. Distinguishing these postures up front lets the
+//! rest of the walk decide whether probing through APQ is worth attempting
+//! at all.
+
+use crate::client::{response_is_persisted_query_not_found, GraphQLClient};
+use std::fmt;
+
+/// What an endpoint does with the `persistedQuery` extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApqSupport {
+    /// The endpoint doesn't recognize the extension at all.
+    Unsupported,
+    /// An unknown hash is accepted once the query text is resent alongside it.
+    AutomaticRegistration,
+    /// The endpoint reports an unknown hash but refuses self-registration,
+    /// meaning only out-of-band-allowlisted hashes will ever execute.
+    Allowlisted,
+}
+
+impl fmt::Display for ApqSupport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ApqSupport::Unsupported => "unsupported",
+            ApqSupport::AutomaticRegistration => "automatic registration",
+            ApqSupport::Allowlisted => "allowlisted (registration rejected)",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A trivial, always-valid query used purely to probe APQ behavior; any
+/// endpoint that serves a GraphQL schema accepts `__typename` on the root.
+const PROBE_QUERY: &str = "{ __typename }";
+
+/// Probe `client` for APQ support without assuming anything about prior
+/// state. If automatic registration works, `client` is left in APQ mode so
+/// every later `query()` call registers/replays through the extension
+/// transparently; in every other case it is left sending plain POST bodies.
+pub async fn detect(client: &GraphQLClient) -> ApqSupport {
+    let hash_only = match client.probe_apq_hash_only(PROBE_QUERY).await {
+        Ok(response) => response,
+        Err(_) => return ApqSupport::Unsupported,
+    };
+
+    if !response_is_persisted_query_not_found(&hash_only) {
+        // Either the server ignored the extension and ran the query anyway
+        // (no APQ support), or it already had this hash registered, which
+        // can't happen for a probe query it's never seen before.
+        return ApqSupport::Unsupported;
+    }
+
+    client.enable_apq();
+    match client.query(PROBE_QUERY).await {
+        Ok(response) if response.errors.is_empty() => ApqSupport::AutomaticRegistration,
+        _ => {
+            // Registration was rejected, so every future query() call would
+            // otherwise burn a wasted round trip for nothing; probing still
+            // can't recover suggestion errors from an allowlisted endpoint,
+            // but at least it fails the same way plain POST probing would.
+            client.disable_apq();
+            ApqSupport::Allowlisted
+        }
+    }
+}
@@ -25,17 +25,45 @@ pub struct Cli {
     pub delay: u64,
 
     /// Custom User-Agent header
-    #[arg(long, default_value = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")]
+    #[arg(
+        long,
+        default_value = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36"
+    )]
     pub user_agent: String,
 
     /// Maximum recursion depth for type walking
     #[arg(long, default_value_t = 10)]
     pub depth: usize,
 
-    /// Number of concurrent requests
+    /// Starting number of concurrent requests for the adaptive probe scheduler
     #[arg(long, default_value_t = 1)]
     pub concurrency: usize,
 
+    /// Floor the adaptive scheduler will back off to under sustained throttling
+    #[arg(long, default_value_t = 1)]
+    pub min_concurrency: usize,
+
+    /// Ceiling the adaptive scheduler may grow concurrency to after windows of
+    /// clean responses
+    #[arg(long, default_value_t = 16)]
+    pub max_concurrency: usize,
+
+    /// Throttle-response rate (0.0-1.0) a window of probes may have and still
+    /// count as "clean" enough to grow concurrency further
+    #[arg(long, default_value_t = 0.05)]
+    pub target_error_rate: f64,
+
+    /// Maximum probe requests per second across all concurrent workers
+    /// (unlimited if unset)
+    #[arg(long)]
+    pub rate_limit: Option<f64>,
+
+    /// Path to a scan-state file; the walk periodically snapshots discovered
+    /// types and probed-type progress here, and resumes from it if it already
+    /// exists, skipping anything already probed
+    #[arg(long)]
+    pub state_file: Option<String>,
+
     /// Run in PoC mode: spin up a local GraphQL server and demonstrate reconstruction
     #[cfg(feature = "poc")]
     #[arg(long)]
@@ -44,4 +72,36 @@ pub struct Cli {
     /// Custom authorization header value (e.g., "Bearer token123")
     #[arg(long)]
     pub auth: Option<String>,
+
+    /// Comma-separated root operation types to probe
+    #[arg(
+        long,
+        default_value = "query,mutation,subscription",
+        value_delimiter = ','
+    )]
+    pub operations: Vec<String>,
+
+    /// Parse the reconstructed SDL with a real GraphQL parser and fail (non-zero
+    /// exit) if it doesn't come out syntactically valid
+    #[arg(long)]
+    pub validate_sdl: bool,
+
+    /// Path to a known-good SDL file to diff the reconstruction against, reporting
+    /// exactly which types/fields were missed or wrongly inferred
+    #[arg(long)]
+    pub import_sdl: Option<String>,
+
+    /// Omit fields whose type was never confirmed by probing and was only
+    /// guessed from the field's name
+    #[arg(long)]
+    pub exclude_inferred_scalars: bool,
+
+    /// Annotate name-guessed fields and unprobed types with a `# uncertain` comment
+    #[arg(long)]
+    pub mark_uncertain: bool,
+
+    /// Omit fields from the exported SDL whose provenance confidence (0.0-1.0)
+    /// falls below this threshold, e.g. to drop noisy SHORT_FIELD_BRUTE hits
+    #[arg(long, default_value_t = 0.0)]
+    pub min_confidence: f64,
 }
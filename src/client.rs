@@ -1,9 +1,70 @@
+use crate::subscription;
+use crate::wordlist;
 use regex::Regex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
 use std::sync::LazyLock;
+use std::sync::Mutex;
 use std::time::Duration;
 
+/// The GraphQL server implementation we believe we're talking to, inferred from
+/// error message phrasing and `extensions.code` values. Different engines phrase
+/// suggestion/required-argument/enum errors differently, so knowing the kind lets
+/// us judge how much confidence to place in the reconstruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ServerKind {
+    /// graphql-js / Apollo Server.
+    GraphqlJs,
+    /// async-graphql (Rust).
+    AsyncGraphql,
+    /// Juniper (Rust).
+    Juniper,
+    /// Could not confidently classify the server.
+    Unknown,
+}
+
+impl fmt::Display for ServerKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ServerKind::GraphqlJs => "graphql-js / Apollo Server",
+            ServerKind::AsyncGraphql => "async-graphql",
+            ServerKind::Juniper => "Juniper",
+            ServerKind::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Inspect a batch of errors and guess the server implementation from
+/// `extensions.code` values and message-format signatures.
+fn fingerprint(errors: &[GraphQLError]) -> ServerKind {
+    for error in errors {
+        if let Some(code) = error.extensions.get("code").and_then(|c| c.as_str()) {
+            if code == "GRAPHQL_VALIDATION_FAILED" {
+                return ServerKind::GraphqlJs;
+            }
+        }
+
+        if error.message.contains("Cannot query field") {
+            // graphql-js/Apollo's default root type is named "Query".
+            return ServerKind::GraphqlJs;
+        }
+
+        if error.message.contains("Unknown field") {
+            // async-graphql's generated root type defaults to "QueryRoot"; Juniper
+            // keeps the wording but never emits a "Did you mean" suggestion.
+            if error.message.contains("QueryRoot") || error.message.contains("Did you mean") {
+                return ServerKind::AsyncGraphql;
+            }
+            return ServerKind::Juniper;
+        }
+    }
+
+    ServerKind::Unknown
+}
+
 /// A single GraphQL error from the response.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct GraphQLError {
@@ -24,6 +85,260 @@ pub struct GraphQLResponse {
     pub errors: Vec<GraphQLError>,
 }
 
+/// Error from a probe HTTP round trip. Distinguishes throttling (HTTP 429,
+/// connection resets/timeouts, GraphQL throttle/complexity errors) from every
+/// other failure, so the adaptive scheduler can back off specifically on
+/// throttle signals instead of treating every failure the same way.
+#[derive(Debug, Clone)]
+pub enum ProbeError {
+    /// The server (or an intermediary) is rate-limiting or complexity-limiting us.
+    Throttled {
+        /// Cooldown requested via a `Retry-After` header, if present.
+        retry_after: Option<Duration>,
+        message: String,
+    },
+    Other(String),
+}
+
+impl ProbeError {
+    /// The `Retry-After` cooldown, if this error represents throttling.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ProbeError::Throttled { retry_after, .. } => *retry_after,
+            ProbeError::Other(_) => None,
+        }
+    }
+
+    pub fn is_throttled(&self) -> bool {
+        matches!(self, ProbeError::Throttled { .. })
+    }
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProbeError::Throttled { message, .. } => write!(f, "{}", message),
+            ProbeError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<String> for ProbeError {
+    fn from(message: String) -> Self {
+        ProbeError::Other(message)
+    }
+}
+
+pub(crate) fn response_is_persisted_query_not_found(response: &GraphQLResponse) -> bool {
+    response.errors.iter().any(|error| {
+        error
+            .extensions
+            .get("code")
+            .and_then(|c| c.as_str())
+            .is_some_and(|code| code == "PERSISTED_QUERY_NOT_FOUND")
+            || error.message.contains("PersistedQueryNotFound")
+    })
+}
+
+/// Parse a `Retry-After` header's value as whole seconds. The HTTP-date form
+/// isn't handled, since every rate limiter this tool has been pointed at so
+/// far sends the numeric-seconds form.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Detect GraphQL-level throttle/complexity-limit errors some engines return
+/// with a 200 status and an `errors[]` entry instead of an HTTP 429.
+fn throttle_error_message(errors: &[GraphQLError]) -> Option<String> {
+    errors.iter().find_map(|error| {
+        let code_is_throttle = error
+            .extensions
+            .get("code")
+            .and_then(|c| c.as_str())
+            .is_some_and(|code| {
+                matches!(
+                    code,
+                    "THROTTLED"
+                        | "RATE_LIMITED"
+                        | "COMPLEXITY_LIMIT_EXCEEDED"
+                        | "QUERY_TOO_COMPLEX"
+                )
+            });
+        let lower = error.message.to_lowercase();
+        let message_mentions_it = lower.contains("rate limit")
+            || lower.contains("too many requests")
+            || lower.contains("query complexity")
+            || lower.contains("throttl");
+        (code_is_throttle || message_mentions_it).then(|| error.message.clone())
+    })
+}
+
+/// Hex-encoded SHA-256 digest of `query`, as used for the `sha256Hash` field
+/// of the `persistedQuery` extension.
+fn sha256_hex(query: &str) -> String {
+    let digest = Sha256::digest(query.as_bytes());
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// A per-engine parser for "Did you mean ...?" field-suggestion errors. Engines
+/// agree on the idea but not the phrasing — quoting style, punctuation, and
+/// whether they suggest at all all vary — so a single regex misses some of them.
+/// `GraphQLClient` tries every registered parser against the first several
+/// error-bearing responses and locks onto whichever one actually matches the
+/// target, then uses only that one for the rest of the run. A confident
+/// `fingerprint()` read short-circuits this: known engines jump straight to
+/// their matching parser (see `parser_index_for_server_kind`) instead of
+/// waiting out the full calibration window.
+pub trait SuggestionParser: Send + Sync {
+    /// Human-readable name for logging/debugging, e.g. "graphql-js".
+    fn name(&self) -> &'static str;
+    /// Try to extract a field suggestion from a single error message.
+    fn parse(&self, message: &str) -> Option<FieldSuggestion>;
+}
+
+/// graphql-js/Apollo and async-graphql both double-quote names:
+///   `Cannot query field "x" on type "Query". Did you mean "a", "b", or "c"?`
+///   `Unknown field "x" on type "QueryRoot". Did you mean "a"?`
+struct DoubleQuotedSuggestionParser;
+
+impl SuggestionParser for DoubleQuotedSuggestionParser {
+    fn name(&self) -> &'static str {
+        "double-quoted (graphql-js/async-graphql)"
+    }
+
+    fn parse(&self, message: &str) -> Option<FieldSuggestion> {
+        let caps = SUGGESTION_RE.captures(message)?;
+        let suggestions_str = caps.get(3).map(|m| m.as_str()).unwrap_or("");
+        let suggestions: Vec<String> = FIELD_NAME_RE
+            .captures_iter(suggestions_str)
+            .map(|c| c.get(1).unwrap().as_str().to_string())
+            .filter(|s| !s.starts_with("__")) // Filter introspection fields
+            .collect();
+
+        if suggestions.is_empty() {
+            return None;
+        }
+
+        Some(FieldSuggestion {
+            queried_field: caps
+                .get(1)
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default(),
+            suggestions,
+            parent_type: caps.get(2).map(|m| m.as_str().to_string()),
+        })
+    }
+}
+
+// Some engines (Ariadne, HotChocolate) single-quote names instead:
+//   `Cannot query field 'x' on type 'Query'. Did you mean 'a' or 'b'?`
+static SINGLE_QUOTED_SUGGESTION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?:Cannot query|Unknown) field '([^']+)' on type '([^']+)'.*?Did you mean ([^?]+)\?"#,
+    )
+    .unwrap()
+});
+static SINGLE_QUOTE_NAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"'([^']+)'"#).unwrap());
+
+struct SingleQuotedSuggestionParser;
+
+impl SuggestionParser for SingleQuotedSuggestionParser {
+    fn name(&self) -> &'static str {
+        "single-quoted (Ariadne/HotChocolate-style)"
+    }
+
+    fn parse(&self, message: &str) -> Option<FieldSuggestion> {
+        let caps = SINGLE_QUOTED_SUGGESTION_RE.captures(message)?;
+        let suggestions_str = caps.get(3).map(|m| m.as_str()).unwrap_or("");
+        let suggestions: Vec<String> = SINGLE_QUOTE_NAME_RE
+            .captures_iter(suggestions_str)
+            .map(|c| c.get(1).unwrap().as_str().to_string())
+            .filter(|s| !s.starts_with("__"))
+            .collect();
+
+        if suggestions.is_empty() {
+            return None;
+        }
+
+        Some(FieldSuggestion {
+            queried_field: caps
+                .get(1)
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default(),
+            suggestions,
+            parent_type: caps.get(2).map(|m| m.as_str().to_string()),
+        })
+    }
+}
+
+// Sangria/Hasura-style phrasing collapses the "or"-joined list into a
+// colon-prefixed "one of:" list:
+//   `Cannot query field "x" on type "Query". Did you mean one of: "a", "b"?`
+static COLON_LIST_SUGGESTION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?:Cannot query|Unknown) field "([^"]+)" on type "([^"]+)".*?Did you mean one of: ([^?]+)\?"#,
+    )
+    .unwrap()
+});
+
+struct ColonListSuggestionParser;
+
+impl SuggestionParser for ColonListSuggestionParser {
+    fn name(&self) -> &'static str {
+        "colon-list (Sangria/Hasura-style)"
+    }
+
+    fn parse(&self, message: &str) -> Option<FieldSuggestion> {
+        let caps = COLON_LIST_SUGGESTION_RE.captures(message)?;
+        let suggestions_str = caps.get(3).map(|m| m.as_str()).unwrap_or("");
+        let suggestions: Vec<String> = FIELD_NAME_RE
+            .captures_iter(suggestions_str)
+            .map(|c| c.get(1).unwrap().as_str().to_string())
+            .filter(|s| !s.starts_with("__"))
+            .collect();
+
+        if suggestions.is_empty() {
+            return None;
+        }
+
+        Some(FieldSuggestion {
+            queried_field: caps
+                .get(1)
+                .map(|m| m.as_str().to_string())
+                .unwrap_or_default(),
+            suggestions,
+            parent_type: caps.get(2).map(|m| m.as_str().to_string()),
+        })
+    }
+}
+
+/// All known suggestion parsers, tried in order until calibration locks onto one.
+fn all_suggestion_parsers() -> Vec<Box<dyn SuggestionParser>> {
+    vec![
+        Box::new(DoubleQuotedSuggestionParser),
+        Box::new(SingleQuotedSuggestionParser),
+        Box::new(ColonListSuggestionParser),
+    ]
+}
+
+/// Map a confidently fingerprinted server kind to the suggestion-parser index
+/// (into `all_suggestion_parsers()`) known to match its phrasing, so a single
+/// fingerprinted response can lock the parser immediately instead of waiting
+/// out `PARSER_CALIBRATION_SAMPLES` empirical samples. Returns `None` for
+/// kinds with no parser to jump to: Juniper's suggestion-free wording isn't
+/// worth locking onto, and `Unknown` carries no signal either way.
+fn parser_index_for_server_kind(kind: ServerKind) -> Option<usize> {
+    match kind {
+        ServerKind::GraphqlJs | ServerKind::AsyncGraphql => Some(0), // DoubleQuotedSuggestionParser
+        ServerKind::Juniper | ServerKind::Unknown => None,
+    }
+}
+
+/// How many error-bearing responses to sample before locking onto a parser.
+const PARSER_CALIBRATION_SAMPLES: usize = 8;
+
 /// Extracted field suggestion from an error message.
 #[derive(Debug, Clone, Serialize)]
 pub struct FieldSuggestion {
@@ -44,6 +359,33 @@ pub struct ObjectTypeHint {
     pub type_name: String,
 }
 
+/// Extracted argument suggestion from an "Unknown argument" error.
+#[derive(Debug, Clone)]
+pub struct ArgSuggestion {
+    /// The field the argument was sent on, e.g. "Query.user"
+    pub field_name: String,
+    /// Valid argument names suggested by the server (may be empty).
+    pub suggestions: Vec<String>,
+}
+
+/// Information extracted from a "required argument" error.
+#[derive(Debug, Clone)]
+pub struct RequiredArgInfo {
+    pub field_name: String,
+    pub arg_name: String,
+    pub type_name: String,
+}
+
+/// Information extracted from an "invalid enum value" error.
+#[derive(Debug, Clone)]
+pub struct EnumHint {
+    /// The enum type name, e.g. "Status".
+    pub enum_name: String,
+    /// Valid enum values suggested by the server (may be empty if the server
+    /// doesn't enumerate them, in which case we've only confirmed the enum exists).
+    pub values: Vec<String>,
+}
+
 // Patterns for extracting suggestions from GraphQL error messages.
 // Common formats:
 //   - "Cannot query field \"xyz\" on type \"Query\". Did you mean \"abc\" or \"def\"?"
@@ -56,8 +398,7 @@ static SUGGESTION_RE: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
-static FIELD_NAME_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#""([^"]+)""#).unwrap());
+static FIELD_NAME_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#""([^"]+)""#).unwrap());
 
 // Pattern for "Field X of type Y must have a selection of subfields"
 // async-graphql: `Field "user" of type "User" must have a selection of subfields`
@@ -66,11 +407,57 @@ static SUBFIELD_RE: LazyLock<Regex> = LazyLock::new(|| {
         .unwrap()
 });
 
+// Pattern for "Unknown argument X on field Y. Did you mean Z?"
+// graphql-js: `Unknown argument "zzz" on field "Query.user". Did you mean "id"?`
+static UNKNOWN_ARG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"Unknown argument "([^"]+)" on field "([^"]+)"\.(?:\s*Did you mean ([^?]+)\?)?"#)
+        .unwrap()
+});
+
+// Pattern for "Field X argument Y of type Z is required, but it was not provided"
+static REQUIRED_ARG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"[Ff]ield "([^"]+)" argument "([^"]+)" of type "([^"]+)" is required, but it was not provided"#,
+    )
+    .unwrap()
+});
+
+// Pattern for an invalid enum value, graphql-js form:
+// `Value "zzzINVALID" does not exist in "Status" enum. Did you mean the enum value "ACTIVE", "INACTIVE", or "PENDING"?`
+static ENUM_VALUE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"Value "([^"]+)" does not exist in "([^"]+)" enum\.(?:\s*Did you mean the enum value ([^?]+)\?)?"#,
+    )
+    .unwrap()
+});
+
+// Pattern for an invalid enum value, async-graphql form, which names the enum
+// but (unlike graphql-js) doesn't enumerate valid values:
+// `failed to parse "zzzINVALID" as Status`
+static ENUM_VALUE_ASYNC_GRAPHQL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"failed to parse "([^"]+)" as ([A-Za-z_]\w*)"#).unwrap());
+
+/// The bogus, unquoted enum value sent to elicit a "does not exist in enum" error.
+pub const ENUM_PROBE_VALUE: &str = "zzzINVALID";
+
 pub struct GraphQLClient {
     client: Client,
     pub endpoint: String,
     delay: Duration,
     auth: Option<String>,
+    /// The server kind we've locked onto, set the first time a response
+    /// fingerprints confidently. `None` until then.
+    server_kind: Mutex<Option<ServerKind>>,
+    /// Index into `all_suggestion_parsers()` once calibration has locked onto one.
+    locked_parser: Mutex<Option<usize>>,
+    /// Per-parser match counts accumulated during calibration.
+    parser_match_counts: Mutex<Vec<usize>>,
+    /// Number of error-bearing responses seen so far, towards `PARSER_CALIBRATION_SAMPLES`.
+    calibration_samples: Mutex<usize>,
+    /// Set once APQ detection confirms the endpoint expects the
+    /// `persistedQuery` extension, so every later `query()` call registers
+    /// (or replays) through it instead of sending plain POST bodies.
+    apq_enabled: Mutex<bool>,
 }
 
 impl GraphQLClient {
@@ -81,24 +468,149 @@ impl GraphQLClient {
             .build()
             .expect("Failed to build HTTP client");
 
+        let parser_count = all_suggestion_parsers().len();
+
         Self {
             client,
             endpoint: endpoint.to_string(),
             delay: Duration::from_millis(delay_ms),
             auth,
+            server_kind: Mutex::new(None),
+            locked_parser: Mutex::new(None),
+            parser_match_counts: Mutex::new(vec![0; parser_count]),
+            calibration_samples: Mutex::new(0),
+            apq_enabled: Mutex::new(false),
         }
     }
 
-    /// Send a GraphQL query and return the parsed response.
-    pub async fn query(&self, query: &str) -> Result<GraphQLResponse, String> {
-        // Rate limiting delay
-        if !self.delay.is_zero() {
-            tokio::time::sleep(self.delay).await;
+    /// The server implementation detected so far, if any response has fingerprinted
+    /// confidently. Once locked onto a kind, later responses don't override it.
+    pub fn detected_server_kind(&self) -> ServerKind {
+        self.server_kind
+            .lock()
+            .unwrap()
+            .unwrap_or(ServerKind::Unknown)
+    }
+
+    /// Feed an error-bearing response through every registered suggestion parser
+    /// and tally which ones matched; after `PARSER_CALIBRATION_SAMPLES` samples,
+    /// lock onto whichever parser matched the most, so the rest of the run skips
+    /// straight to it instead of re-trying every format on every probe.
+    fn calibrate_suggestion_parser(&self, errors: &[GraphQLError]) {
+        let parsers = all_suggestion_parsers();
+        {
+            let mut counts = self.parser_match_counts.lock().unwrap();
+            for (i, parser) in parsers.iter().enumerate() {
+                if errors.iter().any(|e| parser.parse(&e.message).is_some()) {
+                    counts[i] += 1;
+                }
+            }
+        }
+
+        let mut samples = self.calibration_samples.lock().unwrap();
+        *samples += 1;
+        if *samples >= PARSER_CALIBRATION_SAMPLES {
+            let counts = self.parser_match_counts.lock().unwrap();
+            if let Some((best_idx, _)) = counts
+                .iter()
+                .enumerate()
+                .filter(|(_, &count)| count > 0)
+                .max_by_key(|(_, &count)| count)
+            {
+                *self.locked_parser.lock().unwrap() = Some(best_idx);
+            }
         }
+    }
+
+    /// Extract field suggestions from a batch of errors, using the locked-on
+    /// parser once calibration has picked one, or trying every parser until then.
+    fn extract_suggestions(&self, errors: &[GraphQLError]) -> Vec<FieldSuggestion> {
+        if self.locked_parser.lock().unwrap().is_none() && !errors.is_empty() {
+            self.calibrate_suggestion_parser(errors);
+        }
+
+        let parsers = all_suggestion_parsers();
+        let locked = *self.locked_parser.lock().unwrap();
+
+        errors
+            .iter()
+            .filter_map(|error| match locked {
+                Some(idx) => parsers[idx].parse(&error.message),
+                None => parsers.iter().find_map(|p| p.parse(&error.message)),
+            })
+            .map(|mut suggestion| {
+                suggestion.suggestions =
+                    wordlist::rank_suggestions(&suggestion.queried_field, &suggestion.suggestions);
+                suggestion
+            })
+            .collect()
+    }
+
+    /// Send a GraphQL query and return the parsed response. Once APQ has been
+    /// enabled (see `enable_apq`), this registers/replays through the
+    /// `persistedQuery` extension instead of sending the query text outright.
+    pub async fn query(&self, query: &str) -> Result<GraphQLResponse, ProbeError> {
+        if !*self.apq_enabled.lock().unwrap() {
+            return self.send_json(Self::plain_body(query)).await;
+        }
+
+        // Optimistically try the hash alone, as a real APQ client would once a
+        // query is already registered; only pay for a second round trip when
+        // the server reports it as unknown.
+        let hash_only = self.send_json(Self::apq_body(query, false)).await?;
+        if !response_is_persisted_query_not_found(&hash_only) {
+            return Ok(hash_only);
+        }
+
+        self.send_json(Self::apq_body(query, true)).await
+    }
+
+    /// Send a bare `persistedQuery` hash with no accompanying query text, used
+    /// only to detect whether an endpoint recognizes the APQ extension before
+    /// `enable_apq` commits every later `query()` call to using it.
+    pub async fn probe_apq_hash_only(&self, query: &str) -> Result<GraphQLResponse, ProbeError> {
+        self.send_json(Self::apq_body(query, false)).await
+    }
+
+    /// Switch into APQ mode: every subsequent `query()` call registers (or
+    /// replays) through the `persistedQuery` extension rather than sending
+    /// plain POST bodies.
+    pub fn enable_apq(&self) {
+        *self.apq_enabled.lock().unwrap() = true;
+    }
+
+    /// Turn APQ mode back off, e.g. once detection finds the endpoint
+    /// enforces an allowlist and registering through the extension is
+    /// therefore pointless.
+    pub fn disable_apq(&self) {
+        *self.apq_enabled.lock().unwrap() = false;
+    }
+
+    fn plain_body(query: &str) -> serde_json::Value {
+        serde_json::json!({ "query": query })
+    }
 
-        let body = serde_json::json!({
-            "query": query,
+    /// Build an APQ request body: the hash always accompanies the request;
+    /// the query text is only included once `include_query` (i.e. the server
+    /// has already reported `PersistedQueryNotFound` for this hash).
+    fn apq_body(query: &str, include_query: bool) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "extensions": {
+                "persistedQuery": { "version": 1, "sha256Hash": sha256_hex(query) }
+            }
         });
+        if include_query {
+            body["query"] = serde_json::Value::String(query.to_string());
+        }
+        body
+    }
+
+    /// POST a request body and return the parsed response, fingerprinting the
+    /// server kind from it if that hasn't happened yet.
+    async fn send_json(&self, body: serde_json::Value) -> Result<GraphQLResponse, ProbeError> {
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
 
         let mut req = self.client.post(&self.endpoint).json(&body);
 
@@ -106,10 +618,24 @@ impl GraphQLClient {
             req = req.header("Authorization", auth);
         }
 
-        let resp = req
-            .send()
-            .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+        let resp = req.send().await.map_err(|e| {
+            if e.is_connect() || e.is_timeout() {
+                ProbeError::Throttled {
+                    retry_after: None,
+                    message: format!("HTTP error (connection): {}", e),
+                }
+            } else {
+                ProbeError::Other(format!("HTTP error: {}", e))
+            }
+        })?;
+
+        if resp.status().as_u16() == 429 {
+            let retry_after = parse_retry_after(resp.headers());
+            return Err(ProbeError::Throttled {
+                retry_after,
+                message: "HTTP 429 Too Many Requests".to_string(),
+            });
+        }
 
         let text = resp
             .text()
@@ -130,78 +656,291 @@ impl GraphQLClient {
             }
         }
 
+        if self.server_kind.lock().unwrap().is_none() {
+            let kind = fingerprint(&response.errors);
+            if kind != ServerKind::Unknown {
+                *self.server_kind.lock().unwrap() = Some(kind);
+                // Use the fingerprint to skip calibration rather than waiting
+                // for it to empirically rediscover what we already know.
+                if let Some(idx) = parser_index_for_server_kind(kind) {
+                    let mut locked = self.locked_parser.lock().unwrap();
+                    if locked.is_none() {
+                        *locked = Some(idx);
+                    }
+                }
+            }
+        }
+
+        if let Some(message) = throttle_error_message(&response.errors) {
+            return Err(ProbeError::Throttled {
+                retry_after: None,
+                message,
+            });
+        }
+
         Ok(response)
     }
 
     /// Send a raw query string and extract field suggestions from error responses.
-    pub async fn send_probe(&self, query: &str) -> Result<ProbeResult, String> {
+    pub async fn send_probe(&self, query: &str) -> Result<ProbeResult, ProbeError> {
         let response = self.query(query).await?;
-        Ok(parse_probe_response(&response.errors))
+        Ok(self.parse_probe_response(&response.errors))
     }
 
-    /// Probe a field on the root query type.
-    pub async fn probe_root_field(&self, probe_field: &str) -> Result<ProbeResult, String> {
-        let query = format!("{{ {} }}", probe_field);
-        self.send_probe(&query).await
+    /// Check whether `field_name` exists by sending `query` (a bare call to it)
+    /// and looking for an unknown-field error naming it. Used to brute-force
+    /// short field names (see `SHORT_FIELD_BRUTE`) that are too brief to
+    /// reliably trigger a "Did you mean" suggestion: those still produce the
+    /// same "Cannot query field"/"Unknown field" complaint when they don't
+    /// exist, so the absence of that specific complaint means the server
+    /// recognized the field.
+    pub async fn field_exists(&self, query: &str, field_name: &str) -> Result<bool, ProbeError> {
+        let response = self.query(query).await?;
+        let quoted = format!("\"{}\"", field_name);
+        let unknown_field = response.errors.iter().any(|e| {
+            (e.message.contains("Cannot query field") || e.message.contains("Unknown field"))
+                && e.message.contains(&quoted)
+        });
+        Ok(!unknown_field)
     }
 
-}
+    /// Parse all useful information from a batch of GraphQL errors, using the
+    /// calibrated `SuggestionParser` for the "Did you mean?" portion.
+    fn parse_probe_response(&self, errors: &[GraphQLError]) -> ProbeResult {
+        let mut result = ProbeResult {
+            suggestions: self.extract_suggestions(errors),
+            ..ProbeResult::default()
+        };
 
-/// Result of a probe query, containing all extracted information.
-#[derive(Debug, Clone, Default)]
-pub struct ProbeResult {
-    pub suggestions: Vec<FieldSuggestion>,
-    pub object_type_hints: Vec<ObjectTypeHint>,
-}
+        for error in errors {
+            // Check for "must have a selection of subfields" hints
+            if let Some(caps) = SUBFIELD_RE.captures(&error.message) {
+                let field_name = caps
+                    .get(1)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                let type_name = caps
+                    .get(2)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                result.object_type_hints.push(ObjectTypeHint {
+                    field_name,
+                    type_name,
+                });
+            }
 
-/// Parse all useful information from GraphQL error messages.
-fn parse_probe_response(errors: &[GraphQLError]) -> ProbeResult {
-    let mut result = ProbeResult::default();
+            // Check for "Unknown argument" suggestions
+            if let Some(caps) = UNKNOWN_ARG_RE.captures(&error.message) {
+                let field_name = caps
+                    .get(2)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                let suggestions_str = caps.get(3).map(|m| m.as_str()).unwrap_or("");
+                let suggestions: Vec<String> = FIELD_NAME_RE
+                    .captures_iter(suggestions_str)
+                    .map(|c| c.get(1).unwrap().as_str().to_string())
+                    .collect();
+                result.arg_suggestions.push(ArgSuggestion {
+                    field_name,
+                    suggestions,
+                });
+            }
 
-    for error in errors {
-        // Check for field suggestions
-        if let Some(caps) = SUGGESTION_RE.captures(&error.message) {
-            let _field_name = caps.get(1).map(|m| m.as_str().to_string());
-            let parent_type = caps.get(2).map(|m| m.as_str().to_string());
-            let suggestions_str = caps.get(3).map(|m| m.as_str()).unwrap_or("");
-
-            let suggestions: Vec<String> = FIELD_NAME_RE
-                .captures_iter(suggestions_str)
-                .map(|c| c.get(1).unwrap().as_str().to_string())
-                .filter(|s| !s.starts_with("__")) // Filter introspection fields
-                .collect();
-
-            if !suggestions.is_empty() {
-                let queried = caps
+            // Check for "argument X of type Y is required" errors
+            if let Some(caps) = REQUIRED_ARG_RE.captures(&error.message) {
+                let field_name = caps
                     .get(1)
                     .map(|m| m.as_str().to_string())
                     .unwrap_or_default();
-                result.suggestions.push(FieldSuggestion {
-                    queried_field: queried,
-                    suggestions,
-                    parent_type,
+                let arg_name = caps
+                    .get(2)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                let type_name = caps
+                    .get(3)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                result.required_args.push(RequiredArgInfo {
+                    field_name,
+                    arg_name,
+                    type_name,
+                });
+            }
+
+            // Check for invalid enum value errors
+            if let Some(caps) = ENUM_VALUE_RE.captures(&error.message) {
+                let enum_name = caps
+                    .get(2)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                let values_str = caps.get(3).map(|m| m.as_str()).unwrap_or("");
+                let values: Vec<String> = FIELD_NAME_RE
+                    .captures_iter(values_str)
+                    .map(|c| c.get(1).unwrap().as_str().to_string())
+                    .filter(|v| v != ENUM_PROBE_VALUE)
+                    .collect();
+                result.enum_hints.push(EnumHint { enum_name, values });
+            } else if let Some(caps) = ENUM_VALUE_ASYNC_GRAPHQL_RE.captures(&error.message) {
+                // async-graphql confirms the enum exists but doesn't enumerate
+                // its valid values the way graphql-js does.
+                let enum_name = caps
+                    .get(2)
+                    .map(|m| m.as_str().to_string())
+                    .unwrap_or_default();
+                result.enum_hints.push(EnumHint {
+                    enum_name,
+                    values: Vec::new(),
                 });
             }
         }
 
-        // Check for "must have a selection of subfields" hints
-        if let Some(caps) = SUBFIELD_RE.captures(&error.message) {
-            let field_name = caps.get(1).map(|m| m.as_str().to_string()).unwrap_or_default();
-            let type_name = caps.get(2).map(|m| m.as_str().to_string()).unwrap_or_default();
-            result.object_type_hints.push(ObjectTypeHint {
-                field_name,
-                type_name,
-            });
+        result
+    }
+
+    /// Probe a field on a root operation type ("query", "mutation", or "subscription").
+    /// The query shorthand (bare `{ ... }`) is only valid for queries; the other two
+    /// operation kinds must be wrapped in their keyword.
+    pub async fn probe_root_field(
+        &self,
+        operation: &str,
+        probe_field: &str,
+    ) -> Result<ProbeResult, ProbeError> {
+        let query = build_operation_query(operation, probe_field);
+        self.send_probe(&query).await
+    }
+
+    /// Derive the `ws://`/`wss://` endpoint from the HTTP one, for transports
+    /// (subscriptions) that only speak `graphql-transport-ws`.
+    pub fn ws_endpoint(&self) -> String {
+        if let Some(rest) = self.endpoint.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = self.endpoint.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            self.endpoint.clone()
+        }
+    }
+
+    /// Probe many candidate subscription field names in a single
+    /// `graphql-transport-ws` `subscribe` frame, mirroring `probe_batch`'s
+    /// behavior for the transport subscriptions actually require.
+    pub async fn probe_batch_subscription(
+        &self,
+        probe_fields: &[String],
+    ) -> Result<ProbeResult, ProbeError> {
+        let selection_set = probe_fields.join(" ");
+        let query = build_operation_query("subscription", &selection_set);
+        let errors = subscription::probe_errors(&self.ws_endpoint(), &query).await?;
+        Ok(self.parse_probe_response(&errors))
+    }
+
+    /// Send an already-built operation query, dispatching to the
+    /// `graphql-transport-ws` transport for subscriptions -- the only way to
+    /// surface their suggestion errors, per `probe_batch_subscription` -- and
+    /// to plain HTTP `send_probe` for everything else.
+    pub async fn send_probe_for_operation(
+        &self,
+        operation: &str,
+        query: &str,
+    ) -> Result<ProbeResult, ProbeError> {
+        if operation == "subscription" {
+            let errors = subscription::probe_errors(&self.ws_endpoint(), query).await?;
+            Ok(self.parse_probe_response(&errors))
+        } else {
+            self.send_probe(query).await
         }
     }
 
-    result
+    /// Probe many candidate field names on a root operation type in a single
+    /// request. Exploits engines (e.g. async-graphql) whose validator reports
+    /// every unknown-field error from one document in the same response, so a
+    /// batch of a few hundred candidates costs one round trip instead of one
+    /// each. Suggestions come back tagged to their own offending field, so the
+    /// merged `ProbeResult` needs no special handling beyond what a single-field
+    /// probe already produces.
+    pub async fn probe_batch(
+        &self,
+        operation: &str,
+        probe_fields: &[String],
+    ) -> Result<ProbeResult, ProbeError> {
+        let selection_set = probe_fields.join(" ");
+        let query = build_operation_query(operation, &selection_set);
+        self.send_probe(&query).await
+    }
+
+    /// Probe a known field's arguments by sending a junk argument and a bare call,
+    /// reached through `context` (e.g. "user" or "users { edges { node { profile")
+    /// under the given root `operation`, so Mutation/Subscription fields get the
+    /// right wrapping keyword (and Subscription the right transport) instead of
+    /// the bare `{ ... }` shorthand that's only valid for Query.
+    pub async fn probe_field_args(
+        &self,
+        operation: &str,
+        context: &str,
+    ) -> Result<ProbeResult, ProbeError> {
+        let closing = " }".repeat(context.matches('{').count());
+
+        let junk_selection = format!("{}(zzzINVALIDzzz: 1){}", context, closing);
+        let junk_query = build_operation_query(operation, &junk_selection);
+        let mut result = self
+            .send_probe_for_operation(operation, &junk_query)
+            .await?;
+
+        let bare_selection = format!("{}{}", context, closing);
+        let bare_query = build_operation_query(operation, &bare_selection);
+        let bare_result = self
+            .send_probe_for_operation(operation, &bare_query)
+            .await?;
+        result.required_args.extend(bare_result.required_args);
+
+        Ok(result)
+    }
+
+    /// Send a bogus unquoted value for a known argument to elicit an
+    /// "invalid enum value" error, reached through `context` (e.g. "user")
+    /// under the given root `operation` (see `probe_field_args`).
+    pub async fn probe_enum_value(
+        &self,
+        operation: &str,
+        context: &str,
+        arg_name: &str,
+    ) -> Result<ProbeResult, ProbeError> {
+        let closing = " }".repeat(context.matches('{').count());
+        let selection_set = format!("{}({}: {}){}", context, arg_name, ENUM_PROBE_VALUE, closing);
+        let query = build_operation_query(operation, &selection_set);
+        self.send_probe_for_operation(operation, &query).await
+    }
+}
+
+/// Wrap a selection set in the keyword for the given root operation kind.
+/// `query` uses the shorthand form (bare braces); `mutation`/`subscription` require
+/// their keyword to be explicit.
+pub fn build_operation_query(operation: &str, selection_set: &str) -> String {
+    match operation {
+        "mutation" => format!("mutation {{ {} }}", selection_set),
+        "subscription" => format!("subscription {{ {} }}", selection_set),
+        _ => format!("{{ {} }}", selection_set),
+    }
+}
+
+/// Result of a probe query, containing all extracted information.
+#[derive(Debug, Clone, Default)]
+pub struct ProbeResult {
+    pub suggestions: Vec<FieldSuggestion>,
+    pub object_type_hints: Vec<ObjectTypeHint>,
+    pub arg_suggestions: Vec<ArgSuggestion>,
+    pub required_args: Vec<RequiredArgInfo>,
+    pub enum_hints: Vec<EnumHint>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn test_client() -> GraphQLClient {
+        GraphQLClient::new("http://example.test/graphql", "test-agent", 0, None)
+    }
+
     #[test]
     fn test_extract_suggestions_graphql_js() {
         let errors = vec![GraphQLError {
@@ -211,13 +950,10 @@ mod tests {
             locations: vec![],
             extensions: serde_json::Value::Null,
         }];
-        let result = parse_probe_response(&errors);
+        let result = test_client().parse_probe_response(&errors);
         assert_eq!(result.suggestions.len(), 1);
         assert_eq!(result.suggestions[0].suggestions, vec!["user", "users"]);
-        assert_eq!(
-            result.suggestions[0].parent_type,
-            Some("Query".to_string())
-        );
+        assert_eq!(result.suggestions[0].parent_type, Some("Query".to_string()));
     }
 
     #[test]
@@ -228,7 +964,7 @@ mod tests {
             locations: vec![],
             extensions: serde_json::Value::Null,
         }];
-        let result = parse_probe_response(&errors);
+        let result = test_client().parse_probe_response(&errors);
         assert_eq!(result.suggestions.len(), 1);
         assert_eq!(result.suggestions[0].suggestions, vec!["user"]);
         assert_eq!(
@@ -246,7 +982,7 @@ mod tests {
             locations: vec![],
             extensions: serde_json::Value::Null,
         }];
-        let result = parse_probe_response(&errors);
+        let result = test_client().parse_probe_response(&errors);
         assert_eq!(result.suggestions.len(), 1);
         assert_eq!(
             result.suggestions[0].suggestions,
@@ -262,12 +998,118 @@ mod tests {
             locations: vec![],
             extensions: serde_json::Value::Null,
         }];
-        let result = parse_probe_response(&errors);
+        let result = test_client().parse_probe_response(&errors);
         assert_eq!(result.object_type_hints.len(), 1);
         assert_eq!(result.object_type_hints[0].field_name, "user");
         assert_eq!(result.object_type_hints[0].type_name, "User");
     }
 
+    #[test]
+    fn test_build_operation_query() {
+        assert_eq!(build_operation_query("query", "user"), "{ user }");
+        assert_eq!(
+            build_operation_query("mutation", "createUser"),
+            "mutation { createUser }"
+        );
+        assert_eq!(
+            build_operation_query("subscription", "onEvent"),
+            "subscription { onEvent }"
+        );
+    }
+
+    #[test]
+    fn test_extract_unknown_argument_suggestion() {
+        let errors = vec![GraphQLError {
+            message:
+                r#"Unknown argument "zzzINVALIDzzz" on field "Query.user". Did you mean "id"?"#
+                    .to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        let result = test_client().parse_probe_response(&errors);
+        assert_eq!(result.arg_suggestions.len(), 1);
+        assert_eq!(result.arg_suggestions[0].field_name, "Query.user");
+        assert_eq!(result.arg_suggestions[0].suggestions, vec!["id"]);
+    }
+
+    #[test]
+    fn test_extract_required_argument() {
+        let errors = vec![GraphQLError {
+            message:
+                r#"Field "user" argument "id" of type "ID!" is required, but it was not provided."#
+                    .to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        let result = test_client().parse_probe_response(&errors);
+        assert_eq!(result.required_args.len(), 1);
+        assert_eq!(result.required_args[0].field_name, "user");
+        assert_eq!(result.required_args[0].arg_name, "id");
+        assert_eq!(result.required_args[0].type_name, "ID!");
+    }
+
+    #[test]
+    fn test_fingerprint_graphql_js() {
+        let errors = vec![GraphQLError {
+            message: r#"Cannot query field "xuser" on type "Query". Did you mean "user"?"#
+                .to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        assert_eq!(fingerprint(&errors), ServerKind::GraphqlJs);
+    }
+
+    #[test]
+    fn test_fingerprint_async_graphql() {
+        let errors = vec![GraphQLError {
+            message: r#"Unknown field "xuser" on type "QueryRoot". Did you mean "user"?"#
+                .to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        assert_eq!(fingerprint(&errors), ServerKind::AsyncGraphql);
+    }
+
+    #[test]
+    fn test_fingerprint_unknown_when_no_signature_matches() {
+        let errors = vec![GraphQLError {
+            message: "Internal server error".to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        assert_eq!(fingerprint(&errors), ServerKind::Unknown);
+    }
+
+    #[test]
+    fn test_extract_enum_hint() {
+        let errors = vec![GraphQLError {
+            message: r#"Value "zzzINVALID" does not exist in "Status" enum. Did you mean the enum value "ACTIVE", "INACTIVE", or "PENDING"?"#
+                .to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        let result = test_client().parse_probe_response(&errors);
+        assert_eq!(result.enum_hints.len(), 1);
+        assert_eq!(result.enum_hints[0].enum_name, "Status");
+        assert_eq!(
+            result.enum_hints[0].values,
+            vec!["ACTIVE", "INACTIVE", "PENDING"]
+        );
+    }
+
+    #[test]
+    fn test_extract_enum_hint_async_graphql() {
+        let errors = vec![GraphQLError {
+            message: r#"failed to parse "zzzINVALID" as Status"#.to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        let result = test_client().parse_probe_response(&errors);
+        assert_eq!(result.enum_hints.len(), 1);
+        assert_eq!(result.enum_hints[0].enum_name, "Status");
+        assert!(result.enum_hints[0].values.is_empty());
+    }
+
     #[test]
     fn test_filters_introspection_fields() {
         let errors = vec![GraphQLError {
@@ -277,7 +1119,143 @@ mod tests {
             locations: vec![],
             extensions: serde_json::Value::Null,
         }];
-        let result = parse_probe_response(&errors);
+        let result = test_client().parse_probe_response(&errors);
         assert_eq!(result.suggestions[0].suggestions, vec!["user"]);
     }
+
+    #[test]
+    fn test_single_quoted_suggestion_parser() {
+        let parser = SingleQuotedSuggestionParser;
+        let suggestion = parser
+            .parse(r#"Cannot query field 'xuser' on type 'Query'. Did you mean 'user' or 'users'?"#)
+            .unwrap();
+        assert_eq!(suggestion.suggestions, vec!["user", "users"]);
+        assert_eq!(suggestion.parent_type, Some("Query".to_string()));
+
+        assert!(DoubleQuotedSuggestionParser
+            .parse(r#"Cannot query field 'xuser' on type 'Query'. Did you mean 'user'?"#)
+            .is_none());
+    }
+
+    #[test]
+    fn test_colon_list_suggestion_parser() {
+        let parser = ColonListSuggestionParser;
+        let suggestion = parser
+            .parse(r#"Cannot query field "xuser" on type "Query". Did you mean one of: "user", "users"?"#)
+            .unwrap();
+        assert_eq!(suggestion.suggestions, vec!["user", "users"]);
+        assert_eq!(suggestion.parent_type, Some("Query".to_string()));
+    }
+
+    #[test]
+    fn test_calibration_locks_onto_matching_parser() {
+        let client = test_client();
+        let single_quoted_error = vec![GraphQLError {
+            message: r#"Cannot query field 'xuser' on type 'Query'. Did you mean 'user'?"#
+                .to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+
+        for _ in 0..PARSER_CALIBRATION_SAMPLES {
+            assert!(!client.extract_suggestions(&single_quoted_error).is_empty());
+        }
+
+        let locked = client.locked_parser.lock().unwrap();
+        let parsers = all_suggestion_parsers();
+        assert_eq!(
+            parsers[locked.expect("parser should be locked after calibration")].name(),
+            SingleQuotedSuggestionParser.name()
+        );
+    }
+
+    #[test]
+    fn test_parser_index_for_server_kind_matches_double_quoted() {
+        let parsers = all_suggestion_parsers();
+        for kind in [ServerKind::GraphqlJs, ServerKind::AsyncGraphql] {
+            let idx =
+                parser_index_for_server_kind(kind).expect("known kind should map to a parser");
+            assert_eq!(parsers[idx].name(), DoubleQuotedSuggestionParser.name());
+        }
+        assert_eq!(parser_index_for_server_kind(ServerKind::Juniper), None);
+        assert_eq!(parser_index_for_server_kind(ServerKind::Unknown), None);
+    }
+
+    #[test]
+    fn test_sha256_hex_matches_known_digest() {
+        // Standard test vector for SHA-256 of the empty string.
+        assert_eq!(
+            sha256_hex(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_response_is_persisted_query_not_found_by_code() {
+        let response = GraphQLResponse {
+            data: serde_json::Value::Null,
+            errors: vec![GraphQLError {
+                message: "PersistedQueryNotFound".to_string(),
+                locations: vec![],
+                extensions: serde_json::json!({ "code": "PERSISTED_QUERY_NOT_FOUND" }),
+            }],
+        };
+        assert!(response_is_persisted_query_not_found(&response));
+    }
+
+    #[test]
+    fn test_response_is_persisted_query_not_found_ignores_unrelated_errors() {
+        let response = GraphQLResponse {
+            data: serde_json::Value::Null,
+            errors: vec![GraphQLError {
+                message: r#"Cannot query field "xuser" on type "Query"."#.to_string(),
+                locations: vec![],
+                extensions: serde_json::Value::Null,
+            }],
+        };
+        assert!(!response_is_persisted_query_not_found(&response));
+    }
+
+    #[test]
+    fn test_parse_retry_after_numeric_seconds() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "30".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_parse_retry_after_missing_header() {
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_throttle_error_message_matches_known_code() {
+        let errors = vec![GraphQLError {
+            message: "Too many requests, slow down".to_string(),
+            locations: vec![],
+            extensions: serde_json::json!({ "code": "RATE_LIMITED" }),
+        }];
+        assert!(throttle_error_message(&errors).is_some());
+    }
+
+    #[test]
+    fn test_throttle_error_message_matches_message_substring() {
+        let errors = vec![GraphQLError {
+            message: "Query complexity exceeds the maximum allowed".to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        assert!(throttle_error_message(&errors).is_some());
+    }
+
+    #[test]
+    fn test_throttle_error_message_ignores_unrelated_errors() {
+        let errors = vec![GraphQLError {
+            message: r#"Cannot query field "xuser" on type "Query"."#.to_string(),
+            locations: vec![],
+            extensions: serde_json::Value::Null,
+        }];
+        assert!(throttle_error_message(&errors).is_none());
+    }
 }
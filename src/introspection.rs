@@ -0,0 +1,321 @@
+//! Standard GraphQL introspection (`__schema`), tried before falling back to
+//! suggestion-based probing. Some targets disable introspection outright,
+//! others leave it fully reachable, and others filter it so only some types
+//! come back with a usable `fields` list — the latter two cases still save a
+//! lot of probing, so whatever introspection reveals is merged into the
+//! schema up front and probing only fills in the rest.
+
+use crate::client::GraphQLClient;
+use crate::schema::{DiscoveryMethod, ReconstructedSchema};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// How much of the schema standard introspection revealed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum IntrospectionMode {
+    /// `__schema` returned every type with a usable `fields` list; no probing needed.
+    Enabled,
+    /// `__schema` returned nothing usable at all; probing carries the whole run.
+    Disabled,
+    /// `__schema` responded, but at least one type came back without a `fields`
+    /// list (commonly because the server only disables introspection for part
+    /// of the graph) — probing fills in whatever introspection refused.
+    Partial,
+}
+
+impl std::fmt::Display for IntrospectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            IntrospectionMode::Enabled => "enabled",
+            IntrospectionMode::Disabled => "disabled",
+            IntrospectionMode::Partial => "partial",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Outcome of attempting standard introspection.
+pub struct IntrospectionResult {
+    pub mode: IntrospectionMode,
+    /// Schema populated from whatever introspection returned (empty if `Disabled`).
+    pub schema: ReconstructedSchema,
+    /// Names of types introspection fully resolved (had a non-empty `fields`
+    /// list), so the walker can skip probing them.
+    pub fully_known_types: HashSet<String>,
+}
+
+/// The standard introspection query, manually unrolling `ofType` six levels
+/// deep (enough for `[[String!]]!`-style wrapping) since GraphQL has no
+/// recursive fragments.
+const INTROSPECTION_QUERY: &str = r#"
+query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      fields(includeDeprecated: true) {
+        name
+        args { name type { ...TypeRef } }
+        type { ...TypeRef }
+      }
+      enumValues(includeDeprecated: true) { name }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+          ofType {
+            kind
+            name
+            ofType {
+              kind
+              name
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+struct SchemaEnvelope {
+    #[serde(rename = "__schema")]
+    schema: Option<IntrospectionSchema>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IntrospectionSchema {
+    #[serde(rename = "queryType")]
+    query_type: Option<NamedRef>,
+    #[serde(rename = "mutationType")]
+    mutation_type: Option<NamedRef>,
+    #[serde(rename = "subscriptionType")]
+    subscription_type: Option<NamedRef>,
+    types: Vec<IntrospectionType>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedRef {
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IntrospectionType {
+    name: String,
+    kind: String,
+    #[serde(default)]
+    fields: Option<Vec<IntrospectionField>>,
+    #[serde(rename = "enumValues", default)]
+    enum_values: Option<Vec<NamedRef>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IntrospectionField {
+    name: String,
+    #[serde(rename = "type")]
+    field_type: TypeRef,
+    #[serde(default)]
+    args: Vec<IntrospectionArg>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IntrospectionArg {
+    name: String,
+    #[serde(rename = "type")]
+    arg_type: TypeRef,
+}
+
+#[derive(Debug, Deserialize)]
+struct TypeRef {
+    kind: String,
+    name: Option<String>,
+    #[serde(rename = "ofType")]
+    of_type: Option<Box<TypeRef>>,
+}
+
+impl TypeRef {
+    /// Unwrap `NON_NULL`/`LIST` wrappers down to the named type, reporting
+    /// whether any `LIST` layer was seen and whether the outermost layer was
+    /// `NON_NULL`.
+    fn resolve(&self) -> (String, bool, bool) {
+        let mut is_list = false;
+        let required = self.kind == "NON_NULL";
+        let mut current = self;
+        loop {
+            match current.kind.as_str() {
+                "NON_NULL" => {
+                    match &current.of_type {
+                        Some(inner) => current = inner,
+                        None => return (String::new(), is_list, required),
+                    }
+                }
+                "LIST" => {
+                    is_list = true;
+                    match &current.of_type {
+                        Some(inner) => current = inner,
+                        None => return (String::new(), is_list, required),
+                    }
+                }
+                _ => return (current.name.clone().unwrap_or_default(), is_list, required),
+            }
+        }
+    }
+}
+
+/// Attempt standard introspection against `client` and classify how much of
+/// the schema came back, merging whatever did into a fresh `ReconstructedSchema`.
+pub async fn introspect(client: &GraphQLClient) -> IntrospectionResult {
+    let response = match client.query(INTROSPECTION_QUERY).await {
+        Ok(r) => r,
+        Err(_) => return disabled(),
+    };
+
+    let envelope: SchemaEnvelope = match serde_json::from_value(response.data) {
+        Ok(e) => e,
+        Err(_) => return disabled(),
+    };
+
+    let Some(introspected) = envelope.schema else {
+        return disabled();
+    };
+
+    let mut schema = ReconstructedSchema::new();
+    if let Some(name) = introspected.query_type.and_then(|t| t.name) {
+        schema.query_type = name;
+    }
+    schema.mutation_type = introspected.mutation_type.and_then(|t| t.name);
+    schema.subscription_type = introspected.subscription_type.and_then(|t| t.name);
+
+    let mut fully_known_types = HashSet::new();
+    let mut any_type_merged = false;
+    let mut any_gap = false;
+
+    for typ in &introspected.types {
+        if typ.name.starts_with("__") {
+            continue;
+        }
+
+        if typ.kind == "ENUM" {
+            if let Some(values) = &typ.enum_values {
+                let names: Vec<String> = values.iter().filter_map(|v| v.name.clone()).collect();
+                schema.add_enum_values(&typ.name, &names);
+            }
+            continue;
+        }
+
+        if typ.kind != "OBJECT" && typ.kind != "INTERFACE" {
+            continue;
+        }
+
+        match &typ.fields {
+            Some(fields) if !fields.is_empty() => {
+                any_type_merged = true;
+                for field in fields {
+                    schema.add_field(&typ.name, &field.name, DiscoveryMethod::Introspection);
+                    let (type_name, is_list, _required) = field.field_type.resolve();
+                    if !type_name.is_empty() {
+                        schema.set_field_type(&typ.name, &field.name, &type_name);
+                    }
+                    schema.set_field_list(&typ.name, &field.name, is_list);
+
+                    for arg in &field.args {
+                        let (arg_type, _, arg_required) = arg.arg_type.resolve();
+                        schema.add_argument(&typ.name, &field.name, &arg.name);
+                        schema.set_argument_info(
+                            &typ.name,
+                            &field.name,
+                            &arg.name,
+                            if arg_type.is_empty() {
+                                None
+                            } else {
+                                Some(&arg_type)
+                            },
+                            arg_required,
+                        );
+                    }
+                }
+                fully_known_types.insert(typ.name.clone());
+            }
+            _ => any_gap = true,
+        }
+    }
+
+    let mode = if any_type_merged && any_gap {
+        IntrospectionMode::Partial
+    } else if any_type_merged {
+        IntrospectionMode::Enabled
+    } else {
+        IntrospectionMode::Disabled
+    };
+
+    IntrospectionResult {
+        mode,
+        schema,
+        fully_known_types,
+    }
+}
+
+fn disabled() -> IntrospectionResult {
+    IntrospectionResult {
+        mode: IntrospectionMode::Disabled,
+        schema: ReconstructedSchema::new(),
+        fully_known_types: HashSet::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_ref_resolve_plain() {
+        let t = TypeRef {
+            kind: "SCALAR".to_string(),
+            name: Some("String".to_string()),
+            of_type: None,
+        };
+        assert_eq!(t.resolve(), ("String".to_string(), false, false));
+    }
+
+    #[test]
+    fn test_type_ref_resolve_non_null_list() {
+        // [User!]!
+        let t = TypeRef {
+            kind: "NON_NULL".to_string(),
+            name: None,
+            of_type: Some(Box::new(TypeRef {
+                kind: "LIST".to_string(),
+                name: None,
+                of_type: Some(Box::new(TypeRef {
+                    kind: "NON_NULL".to_string(),
+                    name: None,
+                    of_type: Some(Box::new(TypeRef {
+                        kind: "OBJECT".to_string(),
+                        name: Some("User".to_string()),
+                        of_type: None,
+                    })),
+                })),
+            })),
+        };
+        assert_eq!(t.resolve(), ("User".to_string(), true, true));
+    }
+}
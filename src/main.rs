@@ -1,6 +1,11 @@
+mod apq;
 mod cli;
 mod client;
+mod introspection;
 mod schema;
+mod sdl;
+mod state;
+mod subscription;
 mod walker;
 mod wordlist;
 
@@ -10,10 +15,10 @@ mod poc;
 use clap::Parser;
 use cli::Cli;
 use client::GraphQLClient;
-use schema::ReconstructedSchema;
+use schema::{ReconstructedSchema, SdlExportOptions};
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use walker::TypeWalker;
+use walker::{ScalingConfig, TypeWalker};
 
 #[tokio::main]
 async fn main() {
@@ -64,23 +69,62 @@ async fn run_reconstruction(url: &str, args: &Cli) {
     ));
     let schema = Arc::new(Mutex::new(ReconstructedSchema::new()));
 
-    let walker = TypeWalker::new(client, schema.clone(), args.depth);
+    let walker = TypeWalker::new(
+        client.clone(),
+        schema.clone(),
+        args.depth,
+        args.operations.clone(),
+        ScalingConfig {
+            initial_concurrency: args.concurrency,
+            min_concurrency: args.min_concurrency,
+            max_concurrency: args.max_concurrency,
+            target_error_rate: args.target_error_rate,
+        },
+        args.rate_limit,
+        args.state_file.clone(),
+    );
 
     if let Err(e) = walker.run().await {
         eprintln!("[!] Error during reconstruction: {}", e);
         std::process::exit(1);
     }
 
-    let schema = schema.lock().await;
+    let mut schema = schema.lock().await;
+    schema.canonicalize_connections();
 
     // Output SDL
-    let sdl = schema.to_sdl();
-    if let Err(e) = std::fs::write(&args.output, &sdl) {
+    let sdl_options = SdlExportOptions {
+        include_inferred_scalars: !args.exclude_inferred_scalars,
+        mark_uncertain: args.mark_uncertain,
+        min_confidence: args.min_confidence,
+    };
+    let sdl_text = schema.to_sdl_with_options(&sdl_options);
+    if let Err(e) = std::fs::write(&args.output, &sdl_text) {
         eprintln!("[!] Failed to write SDL file: {}", e);
     } else {
         println!("\n[+] SDL schema written to: {}", args.output);
     }
 
+    if args.validate_sdl {
+        match sdl::validate(&sdl_text) {
+            Ok(_) => println!("[+] SDL validation: OK (parses as valid GraphQL)"),
+            Err(e) => {
+                eprintln!("[!] SDL validation failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(import_path) = &args.import_sdl {
+        match std::fs::read_to_string(import_path) {
+            Ok(real_sdl) => match sdl::diff(&real_sdl, &sdl_text) {
+                Ok(report) => print_coverage_report(&report),
+                Err(e) => eprintln!("[!] Failed to diff against {}: {}", import_path, e),
+            },
+            Err(e) => eprintln!("[!] Failed to read {}: {}", import_path, e),
+        }
+    }
+
     // Output JSON
     let json = serde_json::to_string_pretty(&*schema).unwrap();
     if let Err(e) = std::fs::write(&args.json_output, &json) {
@@ -91,6 +135,10 @@ async fn run_reconstruction(url: &str, args: &Cli) {
 
     // Summary
     println!("\n[+] Reconstruction Summary:");
+    println!(
+        "    Detected server: {} (confidence reflects phrasing/extensions heuristics, not certainty)",
+        client.detected_server_kind()
+    );
     println!("    Types discovered: {}", schema.types.len());
     for (type_name, typ) in &schema.types {
         println!("      {} ({} fields)", type_name, typ.fields.len());
@@ -105,13 +153,56 @@ async fn run_reconstruction(url: &str, args: &Cli) {
                 }
                 None => "scalar".to_string(),
             };
-            println!("        - {}: {}", field.name, type_str);
+            println!(
+                "        - {}: {} (confidence: {:.2}, via {:?}, {} confirmation(s))",
+                field.name,
+                type_str,
+                field.provenance.confidence(),
+                field.provenance.method,
+                field.provenance.confirmations
+            );
+        }
+    }
+    println!("    Total discovery probes: {}", schema.discovery_log.len());
+    if args.min_confidence > 0.0 {
+        println!(
+            "    [*] SDL export filtered to fields with confidence >= {:.2}",
+            args.min_confidence
+        );
+    }
+}
+
+/// Print a `--import-sdl` coverage report against a known-good schema.
+fn print_coverage_report(report: &sdl::CoverageReport) {
+    println!("\n[+] Coverage against imported SDL:");
+    println!("    Type coverage: {:.1}%", report.type_coverage_pct());
+    if report.missing_types.is_empty() {
+        println!("    Missing types: none");
+    } else {
+        println!("    Missing types ({}):", report.missing_types.len());
+        for t in &report.missing_types {
+            println!("      - {}", t);
+        }
+    }
+
+    if report.missing_fields.is_empty() {
+        println!("    Missing fields: none");
+    } else {
+        println!("    Missing fields ({}):", report.missing_fields.len());
+        for (type_name, field_name) in &report.missing_fields {
+            println!("      - {}.{}", type_name, field_name);
+        }
+    }
+
+    if !report.extra_types.is_empty() {
+        println!(
+            "    Extra types not in the real schema ({}, likely mis-inferred):",
+            report.extra_types.len()
+        );
+        for t in &report.extra_types {
+            println!("      - {}", t);
         }
     }
-    println!(
-        "    Total discovery probes: {}",
-        schema.discovery_log.len()
-    );
 }
 
 #[cfg(feature = "poc")]
@@ -130,7 +221,7 @@ async fn run_poc_mode(args: &Cli) {
 
     println!("[*] Starting local GraphQL server with introspection DISABLED...");
 
-    let (url, shutdown_tx) = match poc::start_poc_server().await {
+    let (url, shutdown_tx) = match poc::start_poc_server(poc::PocConfig::default()).await {
         Ok(r) => r,
         Err(e) => {
             eprintln!("[!] Failed to start PoC server: {}", e);
@@ -152,7 +243,20 @@ async fn run_poc_mode(args: &Cli) {
         None,
     ));
     let schema = Arc::new(Mutex::new(ReconstructedSchema::new()));
-    let walker = TypeWalker::new(client, schema.clone(), args.depth);
+    let walker = TypeWalker::new(
+        client,
+        schema.clone(),
+        args.depth,
+        args.operations.clone(),
+        ScalingConfig {
+            initial_concurrency: args.concurrency,
+            min_concurrency: args.min_concurrency,
+            max_concurrency: args.max_concurrency,
+            target_error_rate: args.target_error_rate,
+        },
+        args.rate_limit,
+        args.state_file.clone(),
+    );
 
     if let Err(e) = walker.run().await {
         eprintln!("[!] Error during reconstruction: {}", e);
@@ -160,7 +264,8 @@ async fn run_poc_mode(args: &Cli) {
         std::process::exit(1);
     }
 
-    let schema = schema.lock().await;
+    let mut schema = schema.lock().await;
+    schema.canonicalize_connections();
     let reconstructed_sdl = schema.to_sdl();
 
     // Write outputs
@@ -1,10 +1,16 @@
 #![cfg(feature = "poc")]
 
+use actix_web::dev::RequestHead;
 use actix_web::{guard, web, App, HttpResponse, HttpServer};
 use async_graphql::{
-    http::GraphiQLSource, EmptyMutation, EmptySubscription, Object, Schema, SimpleObject, ID,
+    http::GraphiQLSource, EmptyMutation, Object, Schema, ServerError, SimpleObject, Subscription,
+    ID,
 };
-use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLSubscription};
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::sync::Mutex as StdMutex;
+use std::time::{Duration, Instant};
 use tokio::sync::oneshot;
 
 // ─── Schema Types ───────────────────────────────────────────────────
@@ -127,10 +133,129 @@ impl QueryRoot {
     }
 }
 
-type PocSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+pub struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    /// Emits a single `User` event, just enough of a real subscription root
+    /// for a `graphql-transport-ws` client to subscribe against.
+    async fn user_updated(&self) -> impl Stream<Item = User> {
+        stream::once(async {
+            User {
+                id: "1".into(),
+                name: "Alice".into(),
+                email: "alice@example.com".into(),
+                role: "admin".into(),
+                active: true,
+                profile: Profile {
+                    bio: "Engineer".into(),
+                    avatar: "https://example.com/avatar.png".into(),
+                    website: "https://example.com".into(),
+                },
+                orders: vec![],
+            }
+        })
+    }
+}
+
+type PocSchema = Schema<QueryRoot, EmptyMutation, SubscriptionRoot>;
+
+/// Defenses the PoC server can simulate, so the reconstruction engine can be
+/// exercised against more than the single happy-path configuration.
+#[derive(Debug, Clone)]
+pub struct PocConfig {
+    /// If false, "Did you mean ...?" suggestions are stripped from every
+    /// unknown-field error returned over HTTP POST, simulating a server that's
+    /// been hardened against field-suggestion probing. Errors sent over the
+    /// `graphql-transport-ws` WebSocket endpoint are not stripped.
+    pub suggest_fields: bool,
+    /// Caps query nesting via async-graphql's built-in depth limiter, if set.
+    pub max_depth: Option<usize>,
+    /// If true, every request carrying a `persistedQuery` extension is
+    /// rejected with `PersistedQueryNotFound`, regardless of hash or whether
+    /// the full query text was attached — simulating an APQ allowlist that
+    /// never accepts self-registration.
+    pub enforce_persisted_query_allowlist: bool,
+    /// If set, requests beyond this many per second get HTTP 429.
+    pub max_requests_per_second: Option<u32>,
+}
 
-async fn graphql_handler(schema: web::Data<PocSchema>, req: GraphQLRequest) -> GraphQLResponse {
-    schema.execute(req.into_inner()).await.into()
+impl Default for PocConfig {
+    fn default() -> Self {
+        Self {
+            suggest_fields: true,
+            max_depth: None,
+            enforce_persisted_query_allowlist: false,
+            max_requests_per_second: None,
+        }
+    }
+}
+
+/// Tracks request timestamps in a trailing one-second window for the
+/// simulated rate limiter.
+struct RateLimitState {
+    window: StdMutex<VecDeque<Instant>>,
+}
+
+impl RateLimitState {
+    fn new() -> Self {
+        Self {
+            window: StdMutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Records a hit and reports whether it pushes the trailing-second count
+    /// past `limit`.
+    fn hit_exceeds(&self, limit: u32) -> bool {
+        let mut window = self.window.lock().unwrap();
+        let now = Instant::now();
+        while window
+            .front()
+            .is_some_and(|t| now.duration_since(*t) > Duration::from_secs(1))
+        {
+            window.pop_front();
+        }
+        window.push_back(now);
+        window.len() as u32 > limit
+    }
+}
+
+async fn graphql_handler(
+    schema: web::Data<PocSchema>,
+    config: web::Data<PocConfig>,
+    rate_state: web::Data<RateLimitState>,
+    req: GraphQLRequest,
+) -> HttpResponse {
+    if let Some(limit) = config.max_requests_per_second {
+        if rate_state.hit_exceeds(limit) {
+            return HttpResponse::TooManyRequests()
+                .insert_header(("Retry-After", "1"))
+                .finish();
+        }
+    }
+
+    let request = req.into_inner();
+
+    if config.enforce_persisted_query_allowlist && request.extensions.contains_key("persistedQuery")
+    {
+        let response = async_graphql::Response::from_errors(vec![ServerError::new(
+            "PersistedQueryNotFound",
+            None,
+        )]);
+        return HttpResponse::Ok().json(response);
+    }
+
+    let mut response = schema.execute(request).await;
+    if !config.suggest_fields {
+        for error in &mut response.errors {
+            if let Some(idx) = error.message.find(" Did you mean") {
+                error.message.truncate(idx);
+                error.message.push('.');
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(response)
 }
 
 async fn graphiql() -> HttpResponse {
@@ -139,12 +264,36 @@ async fn graphiql() -> HttpResponse {
         .body(GraphiQLSource::build().endpoint("/graphql").finish())
 }
 
+/// Upgrade a `graphql-transport-ws` connection and hand it off to
+/// async-graphql's subscription executor. `GraphQLSubscription` is a request
+/// builder, not an actix `Handler`, so it has to be driven from inside a
+/// regular handler function rather than registered with `.to()` directly.
+async fn graphql_ws_handler(
+    schema: web::Data<PocSchema>,
+    req: actix_web::HttpRequest,
+    payload: web::Payload,
+) -> actix_web::Result<HttpResponse> {
+    GraphQLSubscription::new(schema.get_ref().clone()).start(&req, payload)
+}
+
+/// Distinguishes a `graphql-transport-ws` upgrade request from a plain
+/// GraphiQL page load, both of which are GETs to `/graphql`.
+fn is_websocket_upgrade(head: &RequestHead) -> bool {
+    head.headers()
+        .get("upgrade")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("websocket"))
+}
+
 /// Start the PoC GraphQL server and return its URL + a shutdown handle.
-pub async fn start_poc_server() -> Result<(String, oneshot::Sender<()>), String> {
+pub async fn start_poc_server(config: PocConfig) -> Result<(String, oneshot::Sender<()>), String> {
     // Build schema with introspection disabled
-    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
-        .disable_introspection()
-        .finish();
+    let mut builder =
+        Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot).disable_introspection();
+    if let Some(max_depth) = config.max_depth {
+        builder = builder.limit_depth(max_depth);
+    }
+    let schema = builder.finish();
 
     let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
 
@@ -152,9 +301,14 @@ pub async fn start_poc_server() -> Result<(String, oneshot::Sender<()>), String>
     let addr = format!("127.0.0.1:{}", port);
     let url = format!("http://{}/graphql", addr);
 
+    // Shared by every worker so the limit applies to the server as a whole.
+    let rate_state = web::Data::new(RateLimitState::new());
+
     let server = HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(schema.clone()))
+            .app_data(web::Data::new(config.clone()))
+            .app_data(rate_state.clone())
             .service(
                 web::resource("/graphql")
                     .guard(guard::Post())
@@ -163,8 +317,10 @@ pub async fn start_poc_server() -> Result<(String, oneshot::Sender<()>), String>
             .service(
                 web::resource("/graphql")
                     .guard(guard::Get())
-                    .to(graphiql),
+                    .guard(guard::fn_guard(|ctx| is_websocket_upgrade(ctx.head())))
+                    .to(graphql_ws_handler),
             )
+            .service(web::resource("/graphql").guard(guard::Get()).to(graphiql))
     })
     .bind(&addr)
     .map_err(|e| format!("Failed to bind server: {}", e))?
@@ -204,12 +360,12 @@ async fn find_available_port() -> Result<u16, String> {
 
 /// Generate the real SDL for the PoC schema (for comparison).
 pub fn real_schema_sdl() -> String {
-    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
-        .finish();
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot).finish();
     schema.sdl()
 }
 
-/// Print a side-by-side comparison of real vs reconstructed schema.
+/// Print a side-by-side comparison of real vs reconstructed schema, diffing them
+/// through a real GraphQL parser instead of eyeballing line counts.
 pub fn print_comparison(real_sdl: &str, reconstructed_sdl: &str) {
     println!("\n{}", "=".repeat(80));
     println!("SCHEMA COMPARISON");
@@ -223,35 +379,142 @@ pub fn print_comparison(real_sdl: &str, reconstructed_sdl: &str) {
     println!("{}", "-".repeat(40));
     println!("{}\n", reconstructed_sdl);
 
-    // Count types and fields in both
-    let real_types = count_types(real_sdl);
-    let recon_types = count_types(reconstructed_sdl);
-    let real_fields = count_fields(real_sdl);
-    let recon_fields = count_fields(reconstructed_sdl);
-
     println!("{}", "=".repeat(80));
-    println!("STATISTICS");
+    println!("COVERAGE (parsed diff against real schema)");
     println!("{}", "=".repeat(80));
-    println!("                Real    Reconstructed");
-    println!("  Types:        {:>4}    {:>4}", real_types, recon_types);
-    println!("  Fields:       {:>4}    {:>4}", real_fields, recon_fields);
+
+    match crate::sdl::diff(real_sdl, reconstructed_sdl) {
+        Ok(report) => {
+            println!("  Missing types: {:?}", report.missing_types);
+            println!("  Missing fields: {:?}", report.missing_fields);
+            println!("  Extra (mis-inferred) types: {:?}", report.extra_types);
+        }
+        Err(e) => println!("  [!] Could not diff: {}", e),
+    }
     println!("{}", "=".repeat(80));
 }
 
-fn count_types(sdl: &str) -> usize {
-    sdl.lines().filter(|l| l.starts_with("type ")).count()
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apq::{self, ApqSupport};
+    use crate::client::GraphQLClient;
+    use crate::schema::ReconstructedSchema;
+    use crate::walker::{ScalingConfig, TypeWalker};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    /// Runs full reconstruction against a PoC server started with `config`
+    /// and returns the real and reconstructed SDL for comparison.
+    async fn reconstruct_against(config: PocConfig) -> (String, String) {
+        let (url, shutdown_tx) = start_poc_server(config)
+            .await
+            .expect("PoC server should start");
+
+        let client = Arc::new(GraphQLClient::new(&url, "introspectme-test", 0, None));
+        let schema = Arc::new(Mutex::new(ReconstructedSchema::new()));
+        let walker = TypeWalker::new(
+            client,
+            schema.clone(),
+            5,
+            vec!["query".to_string()],
+            ScalingConfig {
+                initial_concurrency: 4,
+                min_concurrency: 1,
+                max_concurrency: 4,
+                target_error_rate: 0.05,
+            },
+            None,
+            None,
+        );
 
-fn count_fields(sdl: &str) -> usize {
-    sdl.lines()
-        .filter(|l| {
-            let trimmed = l.trim();
-            trimmed.contains(':')
-                && !trimmed.starts_with("type ")
-                && !trimmed.starts_with("schema")
-                && !trimmed.starts_with("query:")
-                && !trimmed.starts_with("mutation:")
-                && !trimmed.starts_with('#')
-        })
-        .count()
+        walker.run().await.expect("reconstruction should not error");
+        let mut schema = schema.lock().await;
+        schema.canonicalize_connections();
+
+        let _ = shutdown_tx.send(());
+        (real_schema_sdl(), schema.to_sdl())
+    }
+
+    #[tokio::test]
+    async fn test_reconstruction_recovers_schema_with_suggestions_enabled() {
+        let (real_sdl, reconstructed_sdl) = reconstruct_against(PocConfig::default()).await;
+        let report = crate::sdl::diff(&real_sdl, &reconstructed_sdl).expect("SDL should parse");
+        assert!(
+            report.missing_types.is_empty(),
+            "expected full type coverage, missing: {:?}",
+            report.missing_types
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconstruction_recovers_little_when_suggestions_suppressed() {
+        let config = PocConfig {
+            suggest_fields: false,
+            ..PocConfig::default()
+        };
+        let (_, reconstructed_sdl) = reconstruct_against(config).await;
+        // With no "Did you mean" text to mine, suggestion-based probing has
+        // nothing to recover beyond whatever a bare type name guesses at.
+        assert!(
+            reconstructed_sdl.trim().is_empty() || !reconstructed_sdl.contains("orders"),
+            "expected little to no recovery with suggestions suppressed, got: {}",
+            reconstructed_sdl
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconstruction_still_finds_root_fields_under_depth_limit() {
+        let config = PocConfig {
+            max_depth: Some(2),
+            ..PocConfig::default()
+        };
+        let (_, reconstructed_sdl) = reconstruct_against(config).await;
+        assert!(reconstructed_sdl.contains("Query"));
+    }
+
+    #[tokio::test]
+    async fn test_persisted_query_allowlist_is_detected_and_falls_back() {
+        let config = PocConfig {
+            enforce_persisted_query_allowlist: true,
+            ..PocConfig::default()
+        };
+        let (url, shutdown_tx) = start_poc_server(config)
+            .await
+            .expect("PoC server should start");
+        let client = GraphQLClient::new(&url, "introspectme-test", 0, None);
+
+        assert_eq!(apq::detect(&client).await, ApqSupport::Allowlisted);
+
+        // Plain (non-APQ) probing should still work once APQ is abandoned.
+        let result = client.send_probe("{ nonexistentField123 }").await;
+        assert!(result.is_ok());
+
+        let _ = shutdown_tx.send(());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_throttles_above_configured_rate() {
+        let config = PocConfig {
+            max_requests_per_second: Some(2),
+            ..PocConfig::default()
+        };
+        let (url, shutdown_tx) = start_poc_server(config)
+            .await
+            .expect("PoC server should start");
+        let client = GraphQLClient::new(&url, "introspectme-test", 0, None);
+
+        let mut saw_throttle = false;
+        for _ in 0..10 {
+            if let Err(e) = client.send_probe("{ __typename }").await {
+                if e.is_throttled() {
+                    saw_throttle = true;
+                    break;
+                }
+            }
+        }
+        assert!(saw_throttle, "expected at least one throttled response");
+
+        let _ = shutdown_tx.send(());
+    }
 }
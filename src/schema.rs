@@ -1,15 +1,15 @@
-use serde::Serialize;
-use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Represents a discovered GraphQL type with its fields.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveredType {
     pub name: String,
     pub fields: BTreeMap<String, FieldInfo>,
 }
 
 /// Information about a discovered field.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldInfo {
     pub name: String,
     /// The return type name if we were able to discover it (by probing subfields).
@@ -17,19 +17,109 @@ pub struct FieldInfo {
     pub type_name: Option<String>,
     /// Whether this field appears to be a list (heuristic based on name patterns).
     pub is_list: bool,
+    /// Arguments accepted by this field, keyed by argument name.
+    pub arguments: BTreeMap<String, ArgInfo>,
+    /// How this field was found and how confident we are that it's real.
+    pub provenance: Provenance,
+}
+
+/// How a field was discovered. Different signals carry very different weight:
+/// the server naming a field in its own "Did you mean?" error is much stronger
+/// evidence than a bare `SHORT_FIELD_BRUTE` guess that merely failed to error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiscoveryMethod {
+    /// Confirmed directly by standard introspection.
+    Introspection,
+    /// Named by the server's own "Did you mean X?" suggestion error.
+    SuggestionError,
+    /// Inferred indirectly, e.g. from a "must have a selection of subfields" hint.
+    ObjectTypeHint,
+    /// A blind existence check against `SHORT_FIELD_BRUTE`; the only signal is
+    /// the absence of an "unknown field" error.
+    BruteForce,
+}
+
+impl DiscoveryMethod {
+    /// Baseline confidence, in `[0, 1]`, for a field discovered solely by this method.
+    fn base_confidence(self) -> f64 {
+        match self {
+            DiscoveryMethod::Introspection => 1.0,
+            DiscoveryMethod::SuggestionError => 0.85,
+            DiscoveryMethod::ObjectTypeHint => 0.7,
+            DiscoveryMethod::BruteForce => 0.3,
+        }
+    }
+}
+
+/// Provenance for a discovered field: how it was found, and how many
+/// independent probe contexts have since turned up the same field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    pub method: DiscoveryMethod,
+    /// Number of independent contexts that have confirmed this field.
+    pub confirmations: u32,
+}
+
+impl Provenance {
+    fn new(method: DiscoveryMethod) -> Self {
+        Self {
+            method,
+            confirmations: 1,
+        }
+    }
+
+    /// Confidence score in `[0, 1]`: the discovery method's baseline, nudged up
+    /// by 0.05 per additional confirming context, capped at 1.0.
+    pub fn confidence(&self) -> f64 {
+        let bonus = 0.05 * f64::from(self.confirmations.saturating_sub(1));
+        (self.method.base_confidence() + bonus).min(1.0)
+    }
+}
+
+/// Information about a discovered field argument.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArgInfo {
+    pub name: String,
+    /// The argument's GraphQL type, including the `!` suffix when known non-null.
+    pub type_name: Option<String>,
+    /// Whether the server reported this argument as required.
+    pub required: bool,
+}
+
+impl ArgInfo {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            type_name: None,
+            required: false,
+        }
+    }
+}
+
+/// A discovered enum type and the values observed across all probes that hit it.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DiscoveredEnum {
+    pub name: String,
+    pub values: BTreeSet<String>,
 }
 
 /// The fully reconstructed schema from probing.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReconstructedSchema {
     pub types: BTreeMap<String, DiscoveredType>,
     pub query_type: String,
+    /// The Mutation root type name, if the server exposes one.
+    pub mutation_type: Option<String>,
+    /// The Subscription root type name, if the server exposes one.
+    pub subscription_type: Option<String>,
+    /// Enum types discovered via invalid-enum-value errors, keyed by enum name.
+    pub enums: BTreeMap<String, DiscoveredEnum>,
     /// Raw discovery log: all suggestions we received.
     pub discovery_log: Vec<DiscoveryEntry>,
 }
 
 /// A single discovery entry for the JSON output.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveryEntry {
     pub parent_type: String,
     pub probed_field: String,
@@ -41,12 +131,36 @@ impl ReconstructedSchema {
         Self {
             types: BTreeMap::new(),
             query_type: "Query".to_string(),
+            mutation_type: None,
+            subscription_type: None,
+            enums: BTreeMap::new(),
             discovery_log: Vec::new(),
         }
     }
 
-    /// Register a discovered field on a type. Returns true if the field was new.
-    pub fn add_field(&mut self, type_name: &str, field_name: &str) -> bool {
+    /// Record enum values discovered for a type, accumulating across probes since
+    /// a single error may only reveal a subset of the enum's members.
+    pub fn add_enum_values(&mut self, enum_name: &str, values: &[String]) {
+        let entry = self
+            .enums
+            .entry(enum_name.to_string())
+            .or_insert_with(|| DiscoveredEnum {
+                name: enum_name.to_string(),
+                values: BTreeSet::new(),
+            });
+        entry.values.extend(values.iter().cloned());
+    }
+
+    /// Register a discovered field on a type, found via `method`. Returns true
+    /// if the field was new; if it already existed, this still records the
+    /// extra confirmation and upgrades its provenance if `method` is stronger
+    /// evidence than whatever found it first.
+    pub fn add_field(
+        &mut self,
+        type_name: &str,
+        field_name: &str,
+        method: DiscoveryMethod,
+    ) -> bool {
         let typ = self
             .types
             .entry(type_name.to_string())
@@ -55,7 +169,11 @@ impl ReconstructedSchema {
                 fields: BTreeMap::new(),
             });
 
-        if typ.fields.contains_key(field_name) {
+        if let Some(existing) = typ.fields.get_mut(field_name) {
+            existing.provenance.confirmations += 1;
+            if method.base_confidence() > existing.provenance.method.base_confidence() {
+                existing.provenance.method = method;
+            }
             return false;
         }
 
@@ -71,6 +189,8 @@ impl ReconstructedSchema {
                 name: field_name.to_string(),
                 type_name: None,
                 is_list,
+                arguments: BTreeMap::new(),
+                provenance: Provenance::new(method),
             },
         );
 
@@ -86,13 +206,55 @@ impl ReconstructedSchema {
         }
     }
 
-    /// Log a discovery for JSON output.
-    pub fn log_discovery(
+    /// Override whether a field is a list. Used when introspection tells us for
+    /// certain, rather than the name-pluralization heuristic `add_field` applies.
+    pub fn set_field_list(&mut self, parent_type: &str, field_name: &str, is_list: bool) {
+        if let Some(typ) = self.types.get_mut(parent_type) {
+            if let Some(field) = typ.fields.get_mut(field_name) {
+                field.is_list = is_list;
+            }
+        }
+    }
+
+    /// Register a discovered argument name on a field.
+    pub fn add_argument(&mut self, type_name: &str, field_name: &str, arg_name: &str) {
+        if let Some(typ) = self.types.get_mut(type_name) {
+            if let Some(field) = typ.fields.get_mut(field_name) {
+                field
+                    .arguments
+                    .entry(arg_name.to_string())
+                    .or_insert_with(|| ArgInfo::new(arg_name));
+            }
+        }
+    }
+
+    /// Record that an argument is required and/or set its GraphQL type.
+    pub fn set_argument_info(
         &mut self,
-        parent_type: &str,
-        probed_field: &str,
-        discovered: &[String],
+        type_name: &str,
+        field_name: &str,
+        arg_name: &str,
+        arg_type: Option<&str>,
+        required: bool,
     ) {
+        if let Some(typ) = self.types.get_mut(type_name) {
+            if let Some(field) = typ.fields.get_mut(field_name) {
+                let arg = field
+                    .arguments
+                    .entry(arg_name.to_string())
+                    .or_insert_with(|| ArgInfo::new(arg_name));
+                if let Some(t) = arg_type {
+                    arg.type_name = Some(t.to_string());
+                }
+                if required {
+                    arg.required = true;
+                }
+            }
+        }
+    }
+
+    /// Log a discovery for JSON output.
+    pub fn log_discovery(&mut self, parent_type: &str, probed_field: &str, discovered: &[String]) {
         self.discovery_log.push(DiscoveryEntry {
             parent_type: parent_type.to_string(),
             probed_field: probed_field.to_string(),
@@ -100,13 +262,26 @@ impl ReconstructedSchema {
         });
     }
 
-    /// Generate SDL (Schema Definition Language) output.
+    /// Generate SDL (Schema Definition Language) output using the default export
+    /// options (inferred scalars included, no uncertainty annotations).
     pub fn to_sdl(&self) -> String {
+        self.to_sdl_with_options(&SdlExportOptions::default())
+    }
+
+    /// Generate SDL output, applying `options` to decide whether fields whose
+    /// type was only guessed from their name get included and/or annotated.
+    pub fn to_sdl_with_options(&self, options: &SdlExportOptions) -> String {
         let mut sdl = String::new();
 
         // Schema definition
         sdl.push_str("schema {\n");
         sdl.push_str(&format!("  query: {}\n", self.query_type));
+        if let Some(mutation_type) = &self.mutation_type {
+            sdl.push_str(&format!("  mutation: {}\n", mutation_type));
+        }
+        if let Some(subscription_type) = &self.subscription_type {
+            sdl.push_str(&format!("  subscription: {}\n", subscription_type));
+        }
         sdl.push_str("}\n\n");
 
         // Sort types so Query comes first, then alphabetical
@@ -123,9 +298,28 @@ impl ReconstructedSchema {
 
         for type_name in type_names {
             let typ = &self.types[type_name];
+
+            let fields: Vec<&FieldInfo> = typ
+                .fields
+                .values()
+                .filter(|f| f.provenance.confidence() >= options.min_confidence)
+                .collect();
+            if fields.is_empty() {
+                continue;
+            }
+
+            if options.mark_uncertain && typ.fields.values().all(|f| f.type_name.is_none()) {
+                sdl.push_str("# uncertain: no subfields were ever probed for this type,\n");
+                sdl.push_str("# every field below is a name-based guess, not a confirmed shape\n");
+            }
             sdl.push_str(&format!("type {} {{\n", type_name));
 
-            for field in typ.fields.values() {
+            for field in fields {
+                let inferred = field.type_name.is_none();
+                if inferred && !options.include_inferred_scalars {
+                    continue;
+                }
+
                 let type_str = match &field.type_name {
                     Some(t) => {
                         if field.is_list {
@@ -139,15 +333,180 @@ impl ReconstructedSchema {
                         infer_scalar_type(&field.name)
                     }
                 };
-                sdl.push_str(&format!("  {}: {}\n", field.name, type_str));
+                let args_str = format_args(&field.arguments);
+                if inferred && options.mark_uncertain {
+                    sdl.push_str(
+                        "  # uncertain: type guessed from field name, never confirmed by probing\n",
+                    );
+                }
+                sdl.push_str(&format!("  {}{}: {}\n", field.name, args_str, type_str));
             }
 
             sdl.push_str("}\n\n");
         }
 
+        for enum_info in self.enums.values() {
+            sdl.push_str(&format!("enum {} {{\n", enum_info.name));
+            for value in &enum_info.values {
+                sdl.push_str(&format!("  {}\n", value));
+            }
+            sdl.push_str("}\n\n");
+        }
+
         sdl.trim_end().to_string()
     }
+}
+
+/// Options controlling what `to_sdl_with_options` includes and annotates.
+#[derive(Debug, Clone, Copy)]
+pub struct SdlExportOptions {
+    /// Include fields whose type was never confirmed by probing and was only
+    /// guessed from the field's name (e.g. `createdAt` -> `DateTime`).
+    pub include_inferred_scalars: bool,
+    /// Prefix fields (and types with no confirmed fields at all) with a `#`
+    /// comment flagging them as uncertain/inferred rather than probe-confirmed.
+    pub mark_uncertain: bool,
+    /// Omit fields whose provenance confidence (see [`Provenance::confidence`])
+    /// falls below this threshold, e.g. to drop noisy `SHORT_FIELD_BRUTE` hits.
+    pub min_confidence: f64,
+}
+
+impl Default for SdlExportOptions {
+    fn default() -> Self {
+        Self {
+            include_inferred_scalars: true,
+            min_confidence: 0.0,
+            mark_uncertain: false,
+        }
+    }
+}
+
+/// Render a field's argument list as `(arg: Type!, ...)`, or an empty string if none.
+fn format_args(arguments: &BTreeMap<String, ArgInfo>) -> String {
+    if arguments.is_empty() {
+        return String::new();
+    }
+
+    let rendered: Vec<String> = arguments
+        .values()
+        .map(|arg| {
+            let mut type_str = arg
+                .type_name
+                .clone()
+                .unwrap_or_else(|| infer_arg_type(&arg.name));
+            if arg.required && !type_str.ends_with('!') {
+                type_str.push('!');
+            }
+            format!("{}: {}", arg.name, type_str)
+        })
+        .collect();
+
+    format!("({})", rendered.join(", "))
+}
+
+/// Canonical `PageInfo` field names recognized by the Relay Cursor Connections spec.
+const PAGE_INFO_FIELDS: &[&str] = &["hasNextPage", "hasPreviousPage", "startCursor", "endCursor"];
+
+impl ReconstructedSchema {
+    /// Detect Relay-style `edges { node cursor }` / `pageInfo` connection shapes and
+    /// rename the anonymous types the walker discovered for them to their canonical
+    /// `XConnection` / `XEdge` / shared `PageInfo` names.
+    pub fn canonicalize_connections(&mut self) {
+        let mut renames: BTreeMap<String, String> = BTreeMap::new();
+        let mut page_info_types: Vec<String> = Vec::new();
+
+        for (type_name, typ) in &self.types {
+            let Some(edges_field) = typ.fields.get("edges") else {
+                continue;
+            };
+            let Some(edge_type_name) = &edges_field.type_name else {
+                continue;
+            };
+            let Some(edge_type) = self.types.get(edge_type_name) else {
+                continue;
+            };
+            if !edge_type.fields.contains_key("node") || !edge_type.fields.contains_key("cursor") {
+                continue;
+            }
+            let Some(node_type_name) = edge_type
+                .fields
+                .get("node")
+                .and_then(|f| f.type_name.clone())
+            else {
+                continue;
+            };
+
+            renames.insert(type_name.clone(), format!("{}Connection", node_type_name));
+            renames.insert(edge_type_name.clone(), format!("{}Edge", node_type_name));
+
+            if let Some(page_info_field) = typ.fields.get("pageInfo") {
+                if let Some(pi_type_name) = &page_info_field.type_name {
+                    if let Some(pi_type) = self.types.get(pi_type_name) {
+                        if pi_type
+                            .fields
+                            .keys()
+                            .all(|f| PAGE_INFO_FIELDS.contains(&f.as_str()))
+                        {
+                            page_info_types.push(pi_type_name.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for pi_type_name in page_info_types {
+            renames.insert(pi_type_name, "PageInfo".to_string());
+        }
+
+        if !renames.is_empty() {
+            self.rename_types(&renames);
+        }
+    }
+
+    /// Apply a type-name rename map across `self.types`, merging fields when two
+    /// original types (e.g. several per-connection `pageInfo` shapes) collapse
+    /// onto the same canonical name.
+    fn rename_types(&mut self, renames: &BTreeMap<String, String>) {
+        let mut new_types: BTreeMap<String, DiscoveredType> = BTreeMap::new();
+
+        for (old_name, mut typ) in std::mem::take(&mut self.types) {
+            let new_name = renames.get(&old_name).cloned().unwrap_or(old_name);
+            typ.name = new_name.clone();
+
+            for field in typ.fields.values_mut() {
+                if let Some(t) = &field.type_name {
+                    if let Some(renamed) = renames.get(t) {
+                        field.type_name = Some(renamed.clone());
+                    }
+                }
+            }
+
+            new_types
+                .entry(new_name)
+                .and_modify(|existing| {
+                    for (field_name, field_info) in typ.fields.clone() {
+                        existing.fields.entry(field_name).or_insert(field_info);
+                    }
+                })
+                .or_insert(typ);
+        }
+
+        self.types = new_types;
+
+        if let Some(renamed) = renames.get(&self.query_type) {
+            self.query_type = renamed.clone();
+        }
+    }
+}
 
+/// Infer the GraphQL type of a Relay pagination argument, falling back to the
+/// general field-name heuristic for everything else.
+fn infer_arg_type(arg_name: &str) -> String {
+    match arg_name {
+        "first" | "last" => "Int".to_string(),
+        "after" | "before" => "String".to_string(),
+        _ => infer_scalar_type(arg_name),
+    }
 }
 
 /// Infer a scalar type from a field name using common naming conventions.
@@ -183,9 +542,7 @@ fn infer_scalar_type(field_name: &str) -> String {
     }
 
     if lower.contains("at")
-        && (lower.contains("created")
-            || lower.contains("updated")
-            || lower.contains("deleted"))
+        && (lower.contains("created") || lower.contains("updated") || lower.contains("deleted"))
     {
         return "DateTime".to_string();
     }
@@ -204,21 +561,21 @@ mod tests {
     #[test]
     fn test_add_field() {
         let mut schema = ReconstructedSchema::new();
-        assert!(schema.add_field("Query", "user"));
-        assert!(!schema.add_field("Query", "user")); // duplicate
-        assert!(schema.add_field("Query", "users"));
+        assert!(schema.add_field("Query", "user", DiscoveryMethod::SuggestionError));
+        assert!(!schema.add_field("Query", "user", DiscoveryMethod::SuggestionError)); // duplicate
+        assert!(schema.add_field("Query", "users", DiscoveryMethod::SuggestionError));
     }
 
     #[test]
     fn test_sdl_output() {
         let mut schema = ReconstructedSchema::new();
-        schema.add_field("Query", "user");
+        schema.add_field("Query", "user", DiscoveryMethod::SuggestionError);
         schema.set_field_type("Query", "user", "User");
-        schema.add_field("Query", "users");
+        schema.add_field("Query", "users", DiscoveryMethod::SuggestionError);
         schema.set_field_type("Query", "users", "User");
-        schema.add_field("User", "id");
-        schema.add_field("User", "name");
-        schema.add_field("User", "email");
+        schema.add_field("User", "id", DiscoveryMethod::SuggestionError);
+        schema.add_field("User", "name", DiscoveryMethod::SuggestionError);
+        schema.add_field("User", "email", DiscoveryMethod::SuggestionError);
 
         let sdl = schema.to_sdl();
         assert!(sdl.contains("type Query {"));
@@ -229,6 +586,151 @@ mod tests {
         assert!(sdl.contains("name: String"));
     }
 
+    #[test]
+    fn test_canonicalize_connections() {
+        let mut schema = ReconstructedSchema::new();
+
+        schema.add_field("Query", "users", DiscoveryMethod::SuggestionError);
+        schema.set_field_type("Query", "users", "UsersList");
+
+        schema.add_field("UsersList", "edges", DiscoveryMethod::SuggestionError);
+        schema.set_field_type("UsersList", "edges", "UsersListEdge");
+        schema.add_field("UsersList", "pageInfo", DiscoveryMethod::SuggestionError);
+        schema.set_field_type("UsersList", "pageInfo", "UsersListPageInfo");
+
+        schema.add_field("UsersListEdge", "node", DiscoveryMethod::SuggestionError);
+        schema.set_field_type("UsersListEdge", "node", "User");
+        schema.add_field("UsersListEdge", "cursor", DiscoveryMethod::SuggestionError);
+
+        schema.add_field(
+            "UsersListPageInfo",
+            "hasNextPage",
+            DiscoveryMethod::SuggestionError,
+        );
+        schema.add_field(
+            "UsersListPageInfo",
+            "endCursor",
+            DiscoveryMethod::SuggestionError,
+        );
+
+        schema.add_field("User", "id", DiscoveryMethod::SuggestionError);
+        schema.add_field("User", "name", DiscoveryMethod::SuggestionError);
+
+        schema.canonicalize_connections();
+
+        assert!(schema.types.contains_key("UserConnection"));
+        assert!(schema.types.contains_key("UserEdge"));
+        assert!(schema.types.contains_key("PageInfo"));
+        assert!(!schema.types.contains_key("UsersList"));
+
+        let users_field = &schema.types["Query"].fields["users"];
+        assert_eq!(users_field.type_name.as_deref(), Some("UserConnection"));
+    }
+
+    #[test]
+    fn test_add_enum_values_accumulates_across_probes() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_enum_values("Status", &["ACTIVE".to_string(), "INACTIVE".to_string()]);
+        schema.add_enum_values("Status", &["PENDING".to_string()]);
+
+        assert_eq!(schema.enums["Status"].values.len(), 3);
+
+        let sdl = schema.to_sdl();
+        assert!(sdl.contains("enum Status {"));
+        assert!(sdl.contains("ACTIVE"));
+        assert!(sdl.contains("PENDING"));
+    }
+
+    #[test]
+    fn test_sdl_export_options_exclude_inferred_scalars() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_field("Query", "user", DiscoveryMethod::SuggestionError);
+        schema.set_field_type("Query", "user", "User");
+        schema.add_field("User", "id", DiscoveryMethod::SuggestionError);
+        schema.set_field_type("User", "id", "ID"); // type confirmed
+        schema.add_field("User", "name", DiscoveryMethod::SuggestionError); // never confirmed, only name-inferred
+
+        let sdl = schema.to_sdl_with_options(&SdlExportOptions {
+            include_inferred_scalars: false,
+            mark_uncertain: false,
+            min_confidence: 0.0,
+        });
+        assert!(!sdl.contains("name:"));
+        assert!(sdl.contains("id: ID"));
+    }
+
+    #[test]
+    fn test_sdl_export_options_mark_uncertain() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_field("Query", "user", DiscoveryMethod::SuggestionError);
+        schema.add_field("User", "name", DiscoveryMethod::SuggestionError);
+
+        let sdl = schema.to_sdl_with_options(&SdlExportOptions {
+            include_inferred_scalars: true,
+            mark_uncertain: true,
+            min_confidence: 0.0,
+        });
+        assert!(sdl.contains("# uncertain: type guessed from field name"));
+        assert!(sdl.contains("# uncertain: no subfields were ever probed"));
+    }
+
+    #[test]
+    fn test_provenance_confidence_by_method() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_field("Query", "user", DiscoveryMethod::SuggestionError);
+        schema.add_field("Query", "xy", DiscoveryMethod::BruteForce);
+
+        let suggested = schema.types["Query"].fields["user"].provenance.confidence();
+        let brute_forced = schema.types["Query"].fields["xy"].provenance.confidence();
+        assert!(suggested > brute_forced);
+    }
+
+    #[test]
+    fn test_provenance_confirmations_raise_confidence_and_upgrade_method() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_field("Query", "user", DiscoveryMethod::BruteForce);
+        let before = schema.types["Query"].fields["user"].provenance.confidence();
+
+        // A stronger signal later confirms the same field.
+        schema.add_field("Query", "user", DiscoveryMethod::SuggestionError);
+        let after = schema.types["Query"].fields["user"].provenance.confidence();
+
+        assert!(after > before);
+        assert_eq!(
+            schema.types["Query"].fields["user"].provenance.method,
+            DiscoveryMethod::SuggestionError
+        );
+        assert_eq!(
+            schema.types["Query"].fields["user"]
+                .provenance
+                .confirmations,
+            2
+        );
+    }
+
+    #[test]
+    fn test_sdl_export_options_min_confidence_filters_low_confidence_fields() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_field("Query", "user", DiscoveryMethod::SuggestionError);
+        schema.add_field("Query", "xy", DiscoveryMethod::BruteForce);
+
+        let sdl = schema.to_sdl_with_options(&SdlExportOptions {
+            min_confidence: 0.5,
+            ..SdlExportOptions::default()
+        });
+        assert!(sdl.contains("user:"));
+        assert!(!sdl.contains("xy:"));
+    }
+
+    #[test]
+    fn test_infer_arg_type_pagination() {
+        assert_eq!(infer_arg_type("first"), "Int");
+        assert_eq!(infer_arg_type("last"), "Int");
+        assert_eq!(infer_arg_type("after"), "String");
+        assert_eq!(infer_arg_type("before"), "String");
+        assert_eq!(infer_arg_type("status"), "String");
+    }
+
     #[test]
     fn test_infer_scalar_types() {
         assert_eq!(infer_scalar_type("id"), "ID");
@@ -0,0 +1,133 @@
+//! Validation and comparison of reconstructed SDL against a real GraphQL parser,
+//! so a broken or incomplete reconstruction fails loudly instead of shipping
+//! silently-wrong schema files.
+
+use graphql_parser::schema::{Definition, Document, TypeDefinition};
+use std::collections::BTreeSet;
+
+/// Parse `sdl` with a real GraphQL schema parser, returning a precise error if it
+/// doesn't form a syntactically valid document. The returned document borrows
+/// from `sdl`, since `graphql_parser`'s AST is invariant over its lifetime.
+pub fn validate(sdl: &str) -> Result<Document<'_, String>, String> {
+    graphql_parser::parse_schema::<String>(sdl).map_err(|e| format!("SDL did not parse: {}", e))
+}
+
+/// The set of object/enum type names and `Type.field` pairs present in a parsed
+/// schema document, used to diff a known schema against our reconstruction.
+#[derive(Debug, Default)]
+struct SchemaShape {
+    types: BTreeSet<String>,
+    fields: BTreeSet<(String, String)>,
+}
+
+fn shape_of(doc: &Document<'_, String>) -> SchemaShape {
+    let mut shape = SchemaShape::default();
+
+    for definition in &doc.definitions {
+        let Definition::TypeDefinition(type_def) = definition else {
+            continue;
+        };
+
+        match type_def {
+            TypeDefinition::Object(obj) => {
+                shape.types.insert(obj.name.clone());
+                for field in &obj.fields {
+                    shape.fields.insert((obj.name.clone(), field.name.clone()));
+                }
+            }
+            TypeDefinition::Enum(e) => {
+                shape.types.insert(e.name.clone());
+            }
+            TypeDefinition::Interface(i) => {
+                shape.types.insert(i.name.clone());
+                for field in &i.fields {
+                    shape.fields.insert((i.name.clone(), field.name.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    shape
+}
+
+/// Coverage of a reconstructed schema against a known-good reference schema.
+#[derive(Debug)]
+pub struct CoverageReport {
+    /// Types present in the real schema that reconstruction never discovered.
+    pub missing_types: Vec<String>,
+    /// `Type.field` pairs present in the real schema but missing from reconstruction.
+    pub missing_fields: Vec<(String, String)>,
+    /// Types reconstruction reported that aren't in the real schema (likely
+    /// mis-inferred anonymous types or naming mismatches).
+    pub extra_types: Vec<String>,
+    /// Total type count in the real schema, for `type_coverage_pct`.
+    pub real_type_count: usize,
+}
+
+impl CoverageReport {
+    pub fn type_coverage_pct(&self) -> f64 {
+        if self.real_type_count == 0 {
+            return 100.0;
+        }
+        let found = self.real_type_count - self.missing_types.len().min(self.real_type_count);
+        (found as f64 / self.real_type_count as f64) * 100.0
+    }
+}
+
+/// Diff a known-good SDL document against our reconstructed SDL, reporting
+/// exactly what was missed and what was (possibly wrongly) invented.
+pub fn diff(real_sdl: &str, reconstructed_sdl: &str) -> Result<CoverageReport, String> {
+    let real_doc = validate(real_sdl)?;
+    let recon_doc = validate(reconstructed_sdl)?;
+
+    let real = shape_of(&real_doc);
+    let recon = shape_of(&recon_doc);
+
+    let missing_types: Vec<String> = real.types.difference(&recon.types).cloned().collect();
+    let extra_types: Vec<String> = recon.types.difference(&real.types).cloned().collect();
+    let missing_fields: Vec<(String, String)> =
+        real.fields.difference(&recon.fields).cloned().collect();
+
+    Ok(CoverageReport {
+        missing_types,
+        missing_fields,
+        extra_types,
+        real_type_count: real.types.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rejects_malformed_sdl() {
+        assert!(validate("type Query { user }").is_err()); // missing field type
+        assert!(validate(
+            "schema { query: Query }\n\ntype Query {\n  user: User\n}\n\ntype User {\n  id: ID\n}"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_diff_reports_missing_and_extra() {
+        let real = r#"
+            schema { query: Query }
+            type Query { user: User }
+            type User { id: ID name: String email: String }
+        "#;
+        let reconstructed = r#"
+            schema { query: Query }
+            type Query { user: User }
+            type User { id: ID name: String }
+        "#;
+
+        let report = diff(real, reconstructed).unwrap();
+        assert!(report.missing_types.is_empty());
+        assert_eq!(
+            report.missing_fields,
+            vec![("User".to_string(), "email".to_string())]
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! On-disk persistence for an in-progress scan, so an interrupted walk (dropped
+//! connection, Ctrl-C) can resume from where it left off instead of starting the
+//! whole wordlist sweep over again.
+
+use crate::schema::ReconstructedSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A type discovered via a parent's fields but not yet probed. Queued rather
+/// than recursed into directly, so that resuming from a snapshot taken after
+/// the parent finished but before its children were visited doesn't orphan
+/// them — they're still sitting in the queue waiting to be popped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingType {
+    pub operation: String,
+    pub type_name: String,
+    pub contexts: Vec<String>,
+    pub depth: usize,
+}
+
+/// Everything needed to resume a walk: the schema discovered so far, the set
+/// of types that have already been fully probed, and the queue of types
+/// still waiting to be probed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanState {
+    pub schema: ReconstructedSchema,
+    pub probed_types: HashSet<String>,
+    #[serde(default)]
+    pub pending_queue: Vec<PendingType>,
+}
+
+impl ScanState {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read state file {}: {}", path, e))?;
+        serde_json::from_str(&text)
+            .map_err(|e| format!("Failed to parse state file {}: {}", path, e))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let text = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize scan state: {}", e))?;
+        std::fs::write(path, text)
+            .map_err(|e| format!("Failed to write state file {}: {}", path, e))
+    }
+}
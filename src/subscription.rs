@@ -0,0 +1,166 @@
+//! `graphql-transport-ws` client used to probe subscription root fields.
+//!
+//! Plain HTTP POST probing works for query and mutation roots because most
+//! servers will still run a field against the validator even though they
+//! refuse to execute it, but subscriptions are commonly rejected outright
+//! over HTTP (some servers require the WebSocket upgrade before the
+//! subscription operation type is even considered), so suggestion errors for
+//! subscription fields only ever show up over the real transport: a
+//! `connection_init` / `connection_ack` handshake, a `subscribe` message
+//! carrying the probe document, then whatever `error`/`next` frames come
+//! back before the operation is torn down with `complete`.
+
+use crate::client::GraphQLError;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::Message;
+
+/// The subprotocol name negotiated via the `Sec-WebSocket-Protocol` header.
+const SUBPROTOCOL: &str = "graphql-transport-ws";
+
+/// How long to wait for a handshake step or a reply before giving up on a probe.
+const FRAME_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage<'a> {
+    ConnectionInit {
+        payload: serde_json::Value,
+    },
+    Subscribe {
+        id: &'a str,
+        payload: SubscribePayload<'a>,
+    },
+    Complete {
+        id: &'a str,
+    },
+}
+
+#[derive(Serialize)]
+struct SubscribePayload<'a> {
+    query: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    ConnectionAck,
+    Next { payload: NextPayload },
+    Error { payload: Vec<GraphQLError> },
+    Complete,
+    Ping,
+    Pong,
+}
+
+#[derive(Deserialize, Default)]
+struct NextPayload {
+    #[serde(default)]
+    errors: Vec<GraphQLError>,
+}
+
+/// Connect to `ws_url`, run the `graphql-transport-ws` handshake, subscribe
+/// with `query`, and collect whatever errors the server reports before the
+/// stream closes. Waiting longer than `FRAME_TIMEOUT` for any frame fails the
+/// whole probe with an error, discarding errors collected so far.
+pub async fn probe_errors(ws_url: &str, query: &str) -> Result<Vec<GraphQLError>, String> {
+    let mut request = ws_url
+        .into_client_request()
+        .map_err(|e| format!("invalid websocket url: {}", e))?;
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        SUBPROTOCOL
+            .parse()
+            .map_err(|_| "invalid subprotocol header value".to_string())?,
+    );
+
+    let (ws_stream, _) =
+        tokio::time::timeout(FRAME_TIMEOUT, tokio_tungstenite::connect_async(request))
+            .await
+            .map_err(|_| "websocket connect timed out".to_string())?
+            .map_err(|e| format!("websocket connect failed: {}", e))?;
+
+    let (mut write, mut read) = ws_stream.split();
+
+    send(
+        &mut write,
+        &ClientMessage::ConnectionInit {
+            payload: serde_json::json!({}),
+        },
+    )
+    .await?;
+
+    // Wait for connection_ack before subscribing; ping/pong and anything
+    // unrecognized are ignored rather than treated as a failed handshake.
+    loop {
+        match next_message(&mut read).await? {
+            Some(ServerMessage::ConnectionAck) => break,
+            Some(_) => continue,
+            None => return Err("connection closed before connection_ack".to_string()),
+        }
+    }
+
+    send(
+        &mut write,
+        &ClientMessage::Subscribe {
+            id: "probe",
+            payload: SubscribePayload { query },
+        },
+    )
+    .await?;
+
+    let mut errors = Vec::new();
+    loop {
+        match next_message(&mut read).await? {
+            Some(ServerMessage::Error { payload }) => {
+                errors.extend(payload);
+                break;
+            }
+            Some(ServerMessage::Next { payload }) => errors.extend(payload.errors),
+            Some(ServerMessage::Complete) | None => break,
+            Some(ServerMessage::ConnectionAck)
+            | Some(ServerMessage::Ping)
+            | Some(ServerMessage::Pong) => {}
+        }
+    }
+
+    let _ = send(&mut write, &ClientMessage::Complete { id: "probe" }).await;
+
+    Ok(errors)
+}
+
+async fn send(
+    write: &mut (impl SinkExt<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin),
+    message: &ClientMessage<'_>,
+) -> Result<(), String> {
+    let text =
+        serde_json::to_string(message).map_err(|e| format!("failed to encode frame: {}", e))?;
+    write
+        .send(Message::Text(text))
+        .await
+        .map_err(|e| format!("websocket send failed: {}", e))
+}
+
+async fn next_message(
+    read: &mut (impl StreamExt<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin),
+) -> Result<Option<ServerMessage>, String> {
+    loop {
+        let frame = match tokio::time::timeout(FRAME_TIMEOUT, read.next()).await {
+            Ok(Some(Ok(frame))) => frame,
+            Ok(Some(Err(e))) => return Err(format!("websocket error: {}", e)),
+            Ok(None) => return Ok(None),
+            Err(_) => return Err("timed out waiting for next frame".to_string()),
+        };
+
+        match frame {
+            Message::Text(text) => match serde_json::from_str(&text) {
+                Ok(message) => return Ok(Some(message)),
+                // Frames of a type we don't model are skipped like ping/pong.
+                Err(_) => continue,
+            },
+            Message::Close(_) => return Ok(None),
+            _ => continue,
+        }
+    }
+}
@@ -1,17 +1,241 @@
-use crate::client::GraphQLClient;
-use crate::schema::ReconstructedSchema;
+use crate::apq::{self, ApqSupport};
+use crate::client;
+use crate::client::{GraphQLClient, ProbeResult};
+use crate::introspection::{self, IntrospectionMode};
+use crate::schema::{DiscoveryMethod, ReconstructedSchema};
+use crate::state::{PendingType, ScanState};
 use crate::wordlist;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Notify};
+use tokio::task::JoinSet;
+
+/// Candidate field names bundled into a single probe document. Engines that
+/// report every unknown-field error from one validation pass (async-graphql,
+/// notably) let us harvest this many suggestions per HTTP round trip instead
+/// of one; kept well under typical request-size/query-complexity limits.
+const PROBE_BATCH_SIZE: usize = 200;
+
+/// Caps the request rate across all concurrent probe tasks to roughly
+/// `requests_per_second`, shared via a single `acquire()` gate rather than one
+/// bucket per task, so the cap holds regardless of how many tasks are in flight.
+struct RateLimiter {
+    min_interval: Duration,
+    last: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Build a limiter from a requests-per-second budget. Returns `None` (no
+    /// limiting) if `requests_per_second` is `None` or non-positive.
+    fn new(requests_per_second: Option<f64>) -> Option<Arc<Self>> {
+        let rps = requests_per_second?;
+        if rps <= 0.0 {
+            return None;
+        }
+        Some(Arc::new(Self {
+            min_interval: Duration::from_secs_f64(1.0 / rps),
+            last: Mutex::new(Instant::now() - Duration::from_secs(3600)),
+        }))
+    }
+
+    async fn acquire(&self) {
+        let mut last = self.last.lock().await;
+        let elapsed = last.elapsed();
+        if elapsed < self.min_interval {
+            tokio::time::sleep(self.min_interval - elapsed).await;
+        }
+        *last = Instant::now();
+    }
+}
+
+/// Knobs for `AdaptiveScheduler`'s AIMD behavior, so the operator can trade
+/// speed for stealth.
+#[derive(Debug, Clone, Copy)]
+pub struct ScalingConfig {
+    pub initial_concurrency: usize,
+    pub min_concurrency: usize,
+    pub max_concurrency: usize,
+    /// Throttle-response rate a window of probes may have and still count as
+    /// clean enough to grow concurrency further.
+    pub target_error_rate: f64,
+}
+
+/// How many completed probes make up one window for the additive-increase
+/// decision.
+const SCHEDULER_WINDOW_SIZE: usize = 20;
+
+/// Base cooldown applied after a throttle signal with no `Retry-After`
+/// header; doubles with each consecutive throttle, up to 16s (the doubling
+/// stops after six consecutive throttles).
+const SCHEDULER_BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+struct SchedulerState {
+    current_limit: usize,
+    in_flight: usize,
+    consecutive_throttles: u32,
+    resume_at: Option<Instant>,
+    window_successes: usize,
+    window_throttles: usize,
+}
+
+/// Dynamically sizes how many probe requests are allowed in flight at once,
+/// reacting to the target's observed tolerance for load instead of a fixed
+/// concurrency cap: additively grows by one after every `SCHEDULER_WINDOW_SIZE`
+/// completed probes whose throttle rate stayed within `target_error_rate`, and
+/// multiplicatively halves (down to `min_concurrency`) the moment it sees a
+/// throttle signal, inserting a cooldown before letting any more requests out
+/// — honoring `Retry-After` when the server gave one, or a backoff that grows
+/// with consecutive throttles otherwise.
+struct AdaptiveScheduler {
+    min_concurrency: usize,
+    max_concurrency: usize,
+    target_error_rate: f64,
+    state: StdMutex<SchedulerState>,
+    notify: Notify,
+}
+
+impl AdaptiveScheduler {
+    fn new(config: ScalingConfig) -> Arc<Self> {
+        let min_concurrency = config.min_concurrency.max(1);
+        let max_concurrency = config.max_concurrency.max(min_concurrency);
+        let initial = config
+            .initial_concurrency
+            .clamp(min_concurrency, max_concurrency);
+
+        Arc::new(Self {
+            min_concurrency,
+            max_concurrency,
+            target_error_rate: config.target_error_rate,
+            state: StdMutex::new(SchedulerState {
+                current_limit: initial,
+                in_flight: 0,
+                consecutive_throttles: 0,
+                resume_at: None,
+                window_successes: 0,
+                window_throttles: 0,
+            }),
+            notify: Notify::new(),
+        })
+    }
+
+    /// Wait for both any active cooldown to elapse and an in-flight slot to
+    /// free up, then reserve one. The returned permit releases its slot (and
+    /// wakes other waiters) when dropped.
+    async fn acquire(self: &Arc<Self>) -> SchedulerPermit {
+        loop {
+            let notified = self.notify.notified();
+
+            let sleep_for = {
+                let mut state = self.state.lock().unwrap();
+                match state.resume_at {
+                    Some(resume_at) if resume_at > Instant::now() => {
+                        Some(resume_at - Instant::now())
+                    }
+                    Some(_) => {
+                        state.resume_at = None;
+                        None
+                    }
+                    None => None,
+                }
+            };
+
+            if let Some(delay) = sleep_for {
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.resume_at.is_none() && state.in_flight < state.current_limit {
+                    state.in_flight += 1;
+                    return SchedulerPermit {
+                        scheduler: self.clone(),
+                    };
+                }
+            }
+
+            notified.await;
+        }
+    }
+
+    /// Record a clean response, growing concurrency by one once a full window
+    /// of probes stayed within the target throttle rate.
+    fn report_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_throttles = 0;
+        state.window_successes += 1;
+
+        if state.window_successes + state.window_throttles >= SCHEDULER_WINDOW_SIZE {
+            let total = state.window_successes + state.window_throttles;
+            let error_rate = state.window_throttles as f64 / total as f64;
+            if error_rate <= self.target_error_rate && state.current_limit < self.max_concurrency {
+                state.current_limit += 1;
+            }
+            state.window_successes = 0;
+            state.window_throttles = 0;
+        }
+    }
+
+    /// Record a throttle signal: halve concurrency immediately and start a
+    /// cooldown before the next request is allowed out.
+    fn report_throttle(&self, retry_after: Option<Duration>) {
+        let mut state = self.state.lock().unwrap();
+        state.current_limit = (state.current_limit / 2).max(self.min_concurrency);
+        state.consecutive_throttles = (state.consecutive_throttles + 1).min(6);
+        let backoff = retry_after
+            .unwrap_or_else(|| SCHEDULER_BASE_BACKOFF * 2u32.pow(state.consecutive_throttles - 1));
+        let resume_at = Instant::now() + backoff;
+        state.resume_at = Some(
+            state
+                .resume_at
+                .map_or(resume_at, |existing| existing.max(resume_at)),
+        );
+        state.window_successes = 0;
+        state.window_throttles += 1;
+    }
+}
+
+/// Reserved in-flight slot from `AdaptiveScheduler::acquire`; releases the
+/// slot and wakes the next waiter when dropped.
+struct SchedulerPermit {
+    scheduler: Arc<AdaptiveScheduler>,
+}
+
+impl Drop for SchedulerPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.scheduler.state.lock().unwrap();
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+        self.scheduler.notify.notify_waiters();
+    }
+}
 
 /// The recursive type walker that discovers the schema by probing fields.
 pub struct TypeWalker {
     client: Arc<GraphQLClient>,
     schema: Arc<Mutex<ReconstructedSchema>>,
     probed_types: Arc<Mutex<HashSet<String>>>,
+    /// Types discovered but not yet probed, persisted alongside `probed_types`
+    /// so a resumed walk picks back up from whatever's still queued instead of
+    /// re-deriving it from whichever parent happened to finish last.
+    work_queue: Arc<Mutex<VecDeque<PendingType>>>,
     max_depth: usize,
+    /// Root operation kinds to attempt, e.g. ["query", "mutation", "subscription"].
+    operations: Vec<String>,
+    /// Adaptively sizes how many probe requests may be in flight at once,
+    /// across every concurrently-probed type, backing off on throttle signals.
+    scheduler: Arc<AdaptiveScheduler>,
+    /// Optional requests/second cap shared by every probe task.
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Path to periodically snapshot scan progress to, and to resume from if it
+    /// already exists when the walk starts.
+    state_file: Option<String>,
+    /// Holds the overall spinner plus one bar per type currently being probed,
+    /// so concurrent type probes render as separate, simultaneously-updating lines.
+    multi: MultiProgress,
     progress: ProgressBar,
 }
 
@@ -20,88 +244,308 @@ impl TypeWalker {
         client: Arc<GraphQLClient>,
         schema: Arc<Mutex<ReconstructedSchema>>,
         max_depth: usize,
+        operations: Vec<String>,
+        scaling: ScalingConfig,
+        rate_limit: Option<f64>,
+        state_file: Option<String>,
     ) -> Self {
-        let progress = ProgressBar::new_spinner();
+        let multi = MultiProgress::new();
+
+        let progress = multi.add(ProgressBar::new_spinner());
         progress.set_style(
             ProgressStyle::default_spinner()
                 .template("{spinner:.green} [{elapsed_precise}] {msg}")
                 .unwrap(),
         );
 
+        let mut probed_types = HashSet::new();
+        let mut pending_queue = VecDeque::new();
+        if let Some(path) = &state_file {
+            if std::path::Path::new(path).exists() {
+                match ScanState::load(path) {
+                    Ok(state) => {
+                        probed_types = state.probed_types;
+                        pending_queue = state.pending_queue.into_iter().collect();
+                        if let Ok(mut locked) = schema.try_lock() {
+                            *locked = state.schema;
+                        }
+                        progress.println(format!(
+                            "[*] Resumed scan state from {} ({} types already probed, {} queued)",
+                            path,
+                            probed_types.len(),
+                            pending_queue.len()
+                        ));
+                    }
+                    Err(e) => {
+                        progress.println(format!(
+                            "[!] Failed to load scan state from {}: {}",
+                            path, e
+                        ));
+                    }
+                }
+            }
+        }
+
         Self {
             client,
             schema,
-            probed_types: Arc::new(Mutex::new(HashSet::new())),
+            probed_types: Arc::new(Mutex::new(probed_types)),
+            work_queue: Arc::new(Mutex::new(pending_queue)),
             max_depth,
+            operations,
+            scheduler: AdaptiveScheduler::new(scaling),
+            rate_limiter: RateLimiter::new(rate_limit),
+            state_file,
+            multi,
             progress,
         }
     }
 
+    /// Snapshot the schema, probed-type set, and pending queue to `state_file`,
+    /// if configured. Called after each type finishes probing so an interrupted
+    /// walk can resume close to where it left off instead of from scratch.
+    async fn snapshot_state(&self) {
+        let Some(path) = &self.state_file else {
+            return;
+        };
+
+        let state = ScanState {
+            schema: self.schema.lock().await.clone(),
+            probed_types: self.probed_types.lock().await.clone(),
+            pending_queue: self.work_queue.lock().await.iter().cloned().collect(),
+        };
+
+        if let Err(e) = state.save(path) {
+            self.progress
+                .println(format!("[!] Failed to save scan state: {}", e));
+        }
+    }
+
+    /// Create a spinner for a single type's probing, rendered alongside any other
+    /// types currently being probed concurrently.
+    fn make_type_bar(&self, label: &str) -> ProgressBar {
+        let bar = self.multi.add(ProgressBar::new_spinner());
+        bar.set_style(
+            ProgressStyle::default_spinner()
+                .template("  {spinner:.cyan} {msg}")
+                .unwrap(),
+        );
+        bar.set_message(label.to_string());
+        bar
+    }
+
     pub async fn run(&self) -> Result<(), String> {
         self.progress
-            .set_message("Starting schema reconstruction...");
+            .set_message("Attempting standard introspection...");
+        let introspected = introspection::introspect(&self.client).await;
+        match introspected.mode {
+            IntrospectionMode::Enabled => {
+                self.progress.println(format!(
+                    "[+] Introspection mode: enabled ({} types recovered authoritatively, no probing needed)",
+                    introspected.schema.types.len()
+                ));
+                *self.schema.lock().await = introspected.schema;
+                self.snapshot_state().await;
+                self.progress
+                    .finish_with_message("Discovery complete via introspection!");
+                return Ok(());
+            }
+            IntrospectionMode::Partial => {
+                self.progress.println(format!(
+                    "[*] Introspection mode: partial ({} types recovered authoritatively, probing the rest)",
+                    introspected.fully_known_types.len()
+                ));
+                {
+                    // Keep whatever a resumed scan state already holds; only
+                    // add the introspected types it doesn't have yet.
+                    let mut schema = self.schema.lock().await;
+                    if schema.types.is_empty() {
+                        *schema = introspected.schema;
+                    } else {
+                        for (name, typ) in introspected.schema.types {
+                            schema.types.entry(name).or_insert(typ);
+                        }
+                    }
+                }
+                self.probed_types
+                    .lock()
+                    .await
+                    .extend(introspected.fully_known_types);
+                self.snapshot_state().await;
+            }
+            IntrospectionMode::Disabled => {
+                self.progress
+                    .println("[-] Introspection mode: disabled, falling back to probing");
+            }
+        }
 
-        // Phase 1: Discover root Query fields
         self.progress
-            .set_message("Phase 1: Probing root Query type...");
-        let (root_type_name, object_fields) = self.probe_root_type().await?;
-        self.schema.lock().await.query_type = root_type_name.clone();
+            .set_message("Checking for persisted queries...");
+        match apq::detect(&self.client).await {
+            ApqSupport::Unsupported => {
+                self.progress
+                    .println("[-] APQ: not supported, probing with plain queries");
+            }
+            support @ ApqSupport::AutomaticRegistration => {
+                self.progress
+                    .println(format!("[+] APQ: {} detected, probing through it", support));
+            }
+            support @ ApqSupport::Allowlisted => {
+                self.progress.println(format!(
+                    "[!] APQ: {} — unregistered probe queries will be rejected regardless of validity",
+                    support
+                ));
+            }
+        }
 
-        // Phase 2: Recursively probe nested types
         self.progress
-            .set_message("Phase 2: Probing nested types...");
+            .set_message("Starting schema reconstruction...");
 
-        for (field_name, type_name) in &object_fields {
-            self.schema
-                .lock()
-                .await
-                .set_field_type(&root_type_name, field_name, type_name);
+        for operation in self.operations.clone() {
+            let default_root = default_root_type_name(&operation);
 
-            // Determine best context queries for this field
-            let contexts = build_root_context_queries(field_name);
-            self.probe_type_recursive(type_name, &contexts, 1).await?;
+            self.progress
+                .set_message(format!("Phase 1: Probing root {} type...", default_root));
+
+            let (root_type_name, object_fields) =
+                match self.probe_root_operation(&operation, &default_root).await? {
+                    Some(result) => result,
+                    None => {
+                        self.progress
+                            .println(format!("  [-] No {} root detected, skipping", default_root));
+                        continue;
+                    }
+                };
+
+            {
+                let mut schema = self.schema.lock().await;
+                match operation.as_str() {
+                    "mutation" => schema.mutation_type = Some(root_type_name.clone()),
+                    "subscription" => schema.subscription_type = Some(root_type_name.clone()),
+                    _ => schema.query_type = root_type_name.clone(),
+                }
+            }
+
+            // Phase 2: queue the types reachable from this root for probing.
+            // Queueing rather than recursing immediately means a crash between
+            // roots still leaves every already-queued type recoverable on resume.
+            self.progress.set_message(format!(
+                "Phase 2: Queueing types nested under {}...",
+                root_type_name
+            ));
+
+            for (field_name, type_name) in &object_fields {
+                let contexts = {
+                    let mut schema = self.schema.lock().await;
+                    schema.set_field_type(&root_type_name, field_name, type_name);
+                    build_root_context_queries(field_name, &schema, &root_type_name)
+                };
+                self.work_queue.lock().await.push_back(PendingType {
+                    operation: operation.clone(),
+                    type_name: type_name.clone(),
+                    contexts,
+                    depth: 1,
+                });
+            }
+            self.snapshot_state().await;
+        }
+
+        // Phase 3: drain the queue of types discovered under any root,
+        // breadth-first. A parent's children are only ever reached through this
+        // queue, never recursed into directly, so resuming after an interrupt
+        // picks back up with whatever's still queued instead of silently
+        // dropping everything below the last snapshot.
+        self.progress
+            .set_message("Phase 3: Probing queued types...");
+        loop {
+            let next = self.work_queue.lock().await.pop_front();
+            let Some(pending) = next else {
+                break;
+            };
+            self.probe_type_once(&pending).await?;
+            self.snapshot_state().await;
         }
 
         let schema = self.schema.lock().await;
         self.progress.finish_with_message(format!(
             "Discovery complete! Found {} types, {} fields",
             schema.types.len(),
-            schema
-                .types
-                .values()
-                .map(|t| t.fields.len())
-                .sum::<usize>()
+            schema.types.values().map(|t| t.fields.len()).sum::<usize>()
         ));
 
         Ok(())
     }
 
-    /// Probe the root Query type.
-    /// Returns (root_type_name, Vec<(field_name, return_type_name)>).
-    async fn probe_root_type(&self) -> Result<(String, Vec<(String, String)>), String> {
+    /// Probe a root operation type (query/mutation/subscription).
+    /// Returns `None` if the operation appears unsupported by the server (no
+    /// suggestions or object-type hints surfaced at all), otherwise
+    /// `(root_type_name, Vec<(field_name, return_type_name)>)`.
+    async fn probe_root_operation(
+        &self,
+        operation: &str,
+        default_root: &str,
+    ) -> Result<Option<(String, Vec<(String, String)>)>, String> {
         let probes = wordlist::full_probe_list();
+        let chunks: Vec<Vec<String>> = probes
+            .chunks(PROBE_BATCH_SIZE)
+            .map(|c| c.to_vec())
+            .collect();
         let mut discovered_fields: HashSet<String> = HashSet::new();
-        let mut root_type_name = "Query".to_string();
+        let mut root_type_name = default_root.to_string();
         let mut object_fields: HashMap<String, String> = HashMap::new();
 
-        let total = probes.len();
-        for (i, probe) in probes.iter().enumerate() {
-            self.progress.set_message(format!(
-                "Probing root: {} [{}/{}]",
-                probe,
-                i + 1,
-                total
+        let bar = self.make_type_bar(&format!("Probing root: {}", default_root));
+        let total = chunks.len();
+        let mut in_flight = JoinSet::new();
+
+        for chunk in chunks {
+            let client = self.client.clone();
+            let operation = operation.to_string();
+            let scheduler = self.scheduler.clone();
+            let rate_limiter = self.rate_limiter.clone();
+            in_flight.spawn(async move {
+                let _permit = scheduler.acquire().await;
+                if let Some(rl) = &rate_limiter {
+                    rl.acquire().await;
+                }
+                // Subscriptions are commonly rejected outright over plain HTTP
+                // POST, so their suggestion errors only surface over the real
+                // `graphql-transport-ws` transport.
+                let result = if operation == "subscription" {
+                    client.probe_batch_subscription(&chunk).await
+                } else {
+                    client.probe_batch(&operation, &chunk).await
+                };
+
+                match &result {
+                    Ok(_) => scheduler.report_success(),
+                    Err(e) if e.is_throttled() => scheduler.report_throttle(e.retry_after()),
+                    Err(_) => scheduler.report_success(),
+                }
+
+                (chunk.len(), result)
+            });
+        }
+
+        let mut completed = 0usize;
+        while let Some(joined) = in_flight.join_next().await {
+            let (chunk_size, outcome) =
+                joined.map_err(|e| format!("probe task panicked: {}", e))?;
+            completed += 1;
+            bar.set_message(format!(
+                "Probing root: {} [chunk {}/{}, {} candidates/chunk]",
+                default_root, completed, total, chunk_size
             ));
 
-            match self.client.probe_root_field(&probe).await {
+            match outcome {
                 Ok(result) => {
                     for suggestion in &result.suggestions {
                         let parent = suggestion
                             .parent_type
                             .clone()
-                            .unwrap_or_else(|| "Query".to_string());
+                            .unwrap_or_else(|| default_root.to_string());
 
-                        if root_type_name == "Query" && parent != "Query" {
+                        if root_type_name == default_root && parent != default_root {
                             root_type_name = parent.clone();
                         }
 
@@ -113,11 +557,16 @@ impl TypeWalker {
 
                         for field_name in &suggestion.suggestions {
                             let mut schema = self.schema.lock().await;
-                            if schema.add_field(&parent, field_name) {
-                                discovered_fields.insert(field_name.clone());
-                                self.progress
-                                    .println(format!("  [+] Found: {}.{}", parent, field_name));
+                            if schema.add_field(
+                                &parent,
+                                field_name,
+                                DiscoveryMethod::SuggestionError,
+                            ) {
+                                bar.println(format!("  [+] Found: {}.{}", parent, field_name));
                             }
+                            // Already-known fields (resumed state, partial
+                            // introspection) still count as surfaced here.
+                            discovered_fields.insert(field_name.clone());
                         }
                     }
 
@@ -125,7 +574,7 @@ impl TypeWalker {
                         object_fields
                             .entry(hint.field_name.clone())
                             .or_insert_with(|| hint.type_name.clone());
-                        self.progress.println(format!(
+                        bar.println(format!(
                             "  [>] Type hint: root.{} -> {}",
                             hint.field_name, hint.type_name
                         ));
@@ -133,12 +582,19 @@ impl TypeWalker {
                 }
                 Err(e) => {
                     if std::env::var("INTROSPECTME_DEBUG").is_ok() {
-                        self.progress
-                            .println(format!("  [!] Root probe error: {}", e));
+                        bar.println(format!("  [!] Root probe error: {}", e));
                     }
                 }
             }
-            self.progress.tick();
+            bar.tick();
+        }
+
+        // Probe each discovered root field for its arguments. Root fields are
+        // selectable directly (no parent path to thread through), so the single
+        // empty context means "no nesting prefix".
+        for field_name in &discovered_fields {
+            self.probe_field_arguments(operation, &root_type_name, field_name, &[String::new()])
+                .await;
         }
 
         // For fields not yet identified as object types, send bare queries to check
@@ -149,15 +605,14 @@ impl TypeWalker {
             .collect();
 
         for field_name in &fields_to_check {
-            self.progress
-                .set_message(format!("Checking type of {}...", field_name));
-            let query = format!("{{ {} }}", field_name);
-            if let Ok(result) = self.client.send_probe(&query).await {
+            bar.set_message(format!("Checking type of {}...", field_name));
+            let query = client::build_operation_query(operation, field_name);
+            if let Ok(result) = self.client.send_probe_for_operation(operation, &query).await {
                 for hint in &result.object_type_hints {
                     object_fields
                         .entry(hint.field_name.clone())
                         .or_insert_with(|| hint.type_name.clone());
-                    self.progress.println(format!(
+                    bar.println(format!(
                         "  [>] Type hint: root.{} -> {}",
                         hint.field_name, hint.type_name
                     ));
@@ -166,26 +621,31 @@ impl TypeWalker {
         }
 
         // Brute-force short root field names
-        self.progress
-            .set_message("Brute-forcing short root fields...".to_string());
+        bar.set_message("Brute-forcing short root fields...".to_string());
         for &short_field in SHORT_FIELD_BRUTE {
             if discovered_fields.contains(short_field) {
                 continue;
             }
-            let query = format!("{{ {} }}", short_field);
+            let query = client::build_operation_query(operation, short_field);
             match self.client.field_exists(&query, short_field).await {
                 Ok(true) => {
                     let mut schema = self.schema.lock().await;
-                    if schema.add_field(&root_type_name, short_field) {
+                    if schema.add_field(&root_type_name, short_field, DiscoveryMethod::BruteForce) {
                         discovered_fields.insert(short_field.to_string());
-                        self.progress
-                            .println(format!("  [+] Found (brute): {}.{}", root_type_name, short_field));
+                        bar.println(format!(
+                            "  [+] Found (brute): {}.{}",
+                            root_type_name, short_field
+                        ));
                     }
 
                     // Also check if this is an object type
                     drop(schema);
-                    let bare_query = format!("{{ {} }}", short_field);
-                    if let Ok(result) = self.client.send_probe(&bare_query).await {
+                    let bare_query = client::build_operation_query(operation, short_field);
+                    if let Ok(result) = self
+                        .client
+                        .send_probe_for_operation(operation, &bare_query)
+                        .await
+                    {
                         for hint in &result.object_type_hints {
                             object_fields
                                 .entry(hint.field_name.clone())
@@ -197,19 +657,130 @@ impl TypeWalker {
             }
         }
 
-        Ok((root_type_name, object_fields.into_iter().collect()))
+        bar.finish_and_clear();
+
+        if discovered_fields.is_empty() && object_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some((root_type_name, object_fields.into_iter().collect())))
     }
 
-    /// Recursively probe a type using the given context queries to reach it.
-    /// `contexts` is a list of query prefixes that can reach this type.
-    /// E.g., for User: ["users", "user(id: \"1\")"]
-    /// For Profile (through User): ["users { profile", "user(id: \"1\") { profile"]
-    async fn probe_type_recursive(
+    /// Probe a single field for its argument signature: send a junk argument to
+    /// elicit an "Unknown argument. Did you mean ...?" suggestion, and a bare call
+    /// to elicit "argument X of type Y is required" errors. `contexts` is the same
+    /// nested query-prefix list used to reach `type_name` itself (see
+    /// `probe_type_once`); the field being probed lives at that same nesting
+    /// depth, not at the root, so each candidate context gets `field_name`
+    /// appended before probing.
+    async fn probe_field_arguments(
         &self,
+        operation: &str,
         type_name: &str,
+        field_name: &str,
         contexts: &[String],
-        depth: usize,
-    ) -> Result<(), String> {
+    ) {
+        let mut arg_names: HashSet<String> = HashSet::new();
+
+        let field_contexts: Vec<String> = contexts
+            .iter()
+            .map(|ctx| {
+                if ctx.is_empty() {
+                    field_name.to_string()
+                } else {
+                    format!("{} {{ {}", ctx, field_name)
+                }
+            })
+            .collect();
+
+        for context in &field_contexts {
+            match self.client.probe_field_args(operation, context).await {
+                Ok(result) => {
+                    let found =
+                        !result.arg_suggestions.is_empty() || !result.required_args.is_empty();
+                    for suggestion in &result.arg_suggestions {
+                        for arg_name in &suggestion.suggestions {
+                            let mut schema = self.schema.lock().await;
+                            schema.add_argument(type_name, field_name, arg_name);
+                            arg_names.insert(arg_name.clone());
+                        }
+                    }
+                    for required in &result.required_args {
+                        let mut schema = self.schema.lock().await;
+                        schema.set_argument_info(
+                            type_name,
+                            field_name,
+                            &required.arg_name,
+                            Some(&required.type_name),
+                            true,
+                        );
+                        arg_names.insert(required.arg_name.clone());
+                        self.progress.println(format!(
+                            "  [>] Required arg: {}.{}({}: {})",
+                            type_name, field_name, required.arg_name, required.type_name
+                        ));
+                    }
+                    if found {
+                        break;
+                    }
+                }
+                Err(_) => {}
+            }
+        }
+
+        // For each discovered argument, send a bogus unquoted value to see whether
+        // the server reports it as an enum (and if so, which values it has), trying
+        // each candidate nested context until one actually elicits a hint.
+        for arg_name in &arg_names {
+            for context in &field_contexts {
+                let Ok(result) = self
+                    .client
+                    .probe_enum_value(operation, context, arg_name)
+                    .await
+                else {
+                    continue;
+                };
+                if result.enum_hints.is_empty() {
+                    continue;
+                }
+                for hint in &result.enum_hints {
+                    let mut schema = self.schema.lock().await;
+                    // `hint.values` may be empty for servers (e.g. async-graphql)
+                    // that confirm the enum exists without enumerating it.
+                    schema.add_enum_values(&hint.enum_name, &hint.values);
+                    schema.set_argument_info(
+                        type_name,
+                        field_name,
+                        arg_name,
+                        Some(&hint.enum_name),
+                        false,
+                    );
+                    self.progress.println(format!(
+                        "  [>] Enum arg: {}.{}({}: {})",
+                        type_name, field_name, arg_name, hint.enum_name
+                    ));
+                }
+                break;
+            }
+        }
+    }
+
+    /// Probe a single queued type using the context queries that can reach it.
+    /// `contexts` is a list of query prefixes that can reach this type.
+    /// E.g., for User: ["users", "user(id: \"1\")"]
+    /// For Profile (through User): ["users { profile", "user(id: \"1\") { profile"]
+    ///
+    /// Child types discovered along the way are pushed onto `self.work_queue`
+    /// rather than probed inline, so a snapshot taken right after this call
+    /// returns always reflects a fully resumable frontier — the parent being
+    /// marked probed and its children being visited are no longer coupled to
+    /// the same call frame.
+    async fn probe_type_once(&self, pending: &PendingType) -> Result<(), String> {
+        let operation = pending.operation.as_str();
+        let type_name = pending.type_name.as_str();
+        let contexts = &pending.contexts;
+        let depth = pending.depth;
+
         if depth > self.max_depth {
             return Ok(());
         }
@@ -221,80 +792,114 @@ impl TypeWalker {
             }
         }
 
-        self.progress.println(format!(
-            "  [*] Probing type: {} (depth {})",
-            type_name, depth
-        ));
+        let bar = self.make_type_bar(&format!("Probing type: {} (depth {})", type_name, depth));
 
         let probes = wordlist::full_probe_list();
+        let chunks: Vec<Vec<String>> = probes
+            .chunks(PROBE_BATCH_SIZE)
+            .map(|c| c.to_vec())
+            .collect();
         let mut discovered_fields: HashSet<String> = HashSet::new();
         let mut child_object_types: HashMap<String, String> = HashMap::new();
 
-        let total = probes.len();
-        for (i, probe) in probes.iter().enumerate() {
-            self.progress.set_message(format!(
-                "Probing {}.{} [{}/{}]",
-                type_name,
-                probe,
-                i + 1,
-                total
-            ));
+        let total = chunks.len();
+        let mut in_flight = JoinSet::new();
 
-            // Try each context query pattern
-            let mut found = false;
-            for ctx in contexts {
-                // Close any open braces in the context with the probe field
-                let query = format!("{{ {} {{ {} }} }}", ctx, probe);
-                let closing_braces = ctx.matches('{').count();
-                let query = format!("{}{}", query, " }".repeat(closing_braces));
-
-                match self.client.send_probe(&query).await {
-                    Ok(result) => {
-                        for suggestion in &result.suggestions {
-                            let parent = suggestion
-                                .parent_type
-                                .clone()
-                                .unwrap_or_else(|| type_name.to_string());
-
-                            if parent != type_name {
-                                continue;
-                            }
+        for chunk in chunks {
+            let client = self.client.clone();
+            let operation = operation.to_string();
+            let contexts = contexts.to_vec();
+            let scheduler = self.scheduler.clone();
+            let rate_limiter = self.rate_limiter.clone();
+            in_flight.spawn(async move {
+                let _permit = scheduler.acquire().await;
+                let mut merged = ProbeResult::default();
+                let probe_fields = chunk.join(" ");
 
-                            self.schema.lock().await.log_discovery(
-                                &parent,
-                                &suggestion.queried_field,
-                                &suggestion.suggestions,
-                            );
-
-                            for field_name in &suggestion.suggestions {
-                                let mut schema = self.schema.lock().await;
-                                if schema.add_field(&parent, field_name) {
-                                    discovered_fields.insert(field_name.clone());
-                                    self.progress.println(format!(
-                                        "  [+] Found: {}.{}",
-                                        parent, field_name
-                                    ));
-                                }
-                            }
-                            found = true;
-                        }
+                // Try each context query pattern, stopping at the first one that
+                // actually suggests a field (cheaper contexts are tried first).
+                for ctx in &contexts {
+                    if let Some(rl) = &rate_limiter {
+                        rl.acquire().await;
+                    }
 
-                        for hint in &result.object_type_hints {
-                            if hint.type_name != type_name {
-                                child_object_types
-                                    .entry(hint.field_name.clone())
-                                    .or_insert_with(|| hint.type_name.clone());
+                    let selection_set = format!("{} {{ {} }}", ctx, probe_fields);
+                    let closing_braces = ctx.matches('{').count();
+                    let query = format!(
+                        "{}{}",
+                        client::build_operation_query(&operation, &selection_set),
+                        " }".repeat(closing_braces)
+                    );
+
+                    match client.send_probe_for_operation(&operation, &query).await {
+                        Ok(result) => {
+                            scheduler.report_success();
+                            let found_suggestion = !result.suggestions.is_empty();
+                            merged.suggestions.extend(result.suggestions);
+                            merged.object_type_hints.extend(result.object_type_hints);
+                            if found_suggestion {
+                                break;
                             }
                         }
+                        Err(e) if e.is_throttled() => scheduler.report_throttle(e.retry_after()),
+                        Err(_) => scheduler.report_success(),
+                    }
+                }
 
-                        if found {
-                            break;
-                        }
+                (chunk.len(), merged)
+            });
+        }
+
+        let mut completed = 0usize;
+        while let Some(joined) = in_flight.join_next().await {
+            let (chunk_size, merged) = joined.map_err(|e| format!("probe task panicked: {}", e))?;
+            completed += 1;
+            bar.set_message(format!(
+                "Probing {} [chunk {}/{}, {} candidates/chunk]",
+                type_name, completed, total, chunk_size
+            ));
+
+            for suggestion in &merged.suggestions {
+                let parent = suggestion
+                    .parent_type
+                    .clone()
+                    .unwrap_or_else(|| type_name.to_string());
+
+                if parent != type_name {
+                    continue;
+                }
+
+                self.schema.lock().await.log_discovery(
+                    &parent,
+                    &suggestion.queried_field,
+                    &suggestion.suggestions,
+                );
+
+                for field_name in &suggestion.suggestions {
+                    let mut schema = self.schema.lock().await;
+                    if schema.add_field(&parent, field_name, DiscoveryMethod::SuggestionError) {
+                        discovered_fields.insert(field_name.clone());
+                        bar.println(format!("  [+] Found: {}.{}", parent, field_name));
                     }
-                    Err(_) => {}
                 }
             }
-            self.progress.tick();
+
+            for hint in &merged.object_type_hints {
+                if hint.type_name != type_name {
+                    child_object_types
+                        .entry(hint.field_name.clone())
+                        .or_insert_with(|| hint.type_name.clone());
+                }
+            }
+
+            bar.tick();
+        }
+
+        // Probe each discovered field on this type for its arguments, reached
+        // through the same nested contexts used to reach the type itself.
+        for field_name in &discovered_fields {
+            self.probe_field_arguments(operation, type_name, field_name, contexts)
+                .await;
         }
 
         // Check discovered fields for object types
@@ -306,17 +911,25 @@ impl TypeWalker {
 
         for field_name in &fields_to_check {
             for ctx in contexts {
-                let query = format!("{{ {} {{ {} }} }}", ctx, field_name);
+                let selection_set = format!("{} {{ {} }}", ctx, field_name);
                 let closing_braces = ctx.matches('{').count();
-                let query = format!("{}{}", query, " }".repeat(closing_braces));
+                let query = format!(
+                    "{}{}",
+                    client::build_operation_query(operation, &selection_set),
+                    " }".repeat(closing_braces)
+                );
 
-                if let Ok(result) = self.client.send_probe(&query).await {
+                if let Ok(result) = self
+                    .client
+                    .send_probe_for_operation(operation, &query)
+                    .await
+                {
                     for hint in &result.object_type_hints {
                         if hint.field_name == *field_name {
                             child_object_types
                                 .entry(hint.field_name.clone())
                                 .or_insert_with(|| hint.type_name.clone());
-                            self.progress.println(format!(
+                            bar.println(format!(
                                 "  [>] Type hint: {}.{} -> {}",
                                 type_name, hint.field_name, hint.type_name
                             ));
@@ -332,13 +945,14 @@ impl TypeWalker {
         // Phase 3: Brute-force short field names that are too brief for suggestions.
         // For each short name, send a direct query and check if the server
         // recognizes it (no "Unknown field" error).
-        self.progress.set_message(format!(
-            "Brute-forcing short fields on {}...",
-            type_name
-        ));
+        bar.set_message(format!("Brute-forcing short fields on {}...", type_name));
         for &short_field in SHORT_FIELD_BRUTE {
             // Skip if already discovered
-            if self.schema.lock().await.types
+            if self
+                .schema
+                .lock()
+                .await
+                .types
                 .get(type_name)
                 .map(|t| t.fields.contains_key(short_field))
                 .unwrap_or(false)
@@ -348,16 +962,22 @@ impl TypeWalker {
 
             // Try each context
             for ctx in contexts {
-                let query = format!("{{ {} {{ {} }} }}", ctx, short_field);
+                let selection_set = format!("{} {{ {} }}", ctx, short_field);
                 let closing_braces = ctx.matches('{').count();
-                let query = format!("{}{}", query, " }".repeat(closing_braces));
+                let query = format!(
+                    "{}{}",
+                    client::build_operation_query(operation, &selection_set),
+                    " }".repeat(closing_braces)
+                );
 
                 match self.client.field_exists(&query, short_field).await {
                     Ok(true) => {
                         let mut schema = self.schema.lock().await;
-                        if schema.add_field(type_name, short_field) {
-                            self.progress
-                                .println(format!("  [+] Found (brute): {}.{}", type_name, short_field));
+                        if schema.add_field(type_name, short_field, DiscoveryMethod::BruteForce) {
+                            bar.println(format!(
+                                "  [+] Found (brute): {}.{}",
+                                type_name, short_field
+                            ));
                         }
                         break;
                     }
@@ -366,7 +986,12 @@ impl TypeWalker {
             }
         }
 
-        // Recurse into child types
+        bar.finish_and_clear();
+
+        // Queue child types for later probing instead of recursing inline, so
+        // the Phase 3 drain loop in `run()` snapshots after every unit of
+        // work and a resume never orphans a type whose parent already
+        // finished.
         for (field_name, child_type) in &child_object_types {
             self.schema
                 .lock()
@@ -379,8 +1004,12 @@ impl TypeWalker {
                 .map(|ctx| format!("{} {{ {}", ctx, field_name))
                 .collect();
 
-            Box::pin(self.probe_type_recursive(child_type, &child_contexts, depth + 1))
-                .await?;
+            self.work_queue.lock().await.push_back(PendingType {
+                operation: operation.to_string(),
+                type_name: child_type.clone(),
+                contexts: child_contexts,
+                depth: depth + 1,
+            });
         }
 
         Ok(())
@@ -390,27 +1019,60 @@ impl TypeWalker {
 /// Short / common field names that are too brief to trigger "Did you mean" suggestions.
 /// We brute-force these by checking if the server returns "Unknown field" or not.
 const SHORT_FIELD_BRUTE: &[&str] = &[
-    "id", "pk", "key", "uid", "me", "ok", "ip", "to", "cc", "by",
-    "on", "at", "of", "in", "up", "no", "do", "is", "or", "as",
-    "ref", "url", "uri", "tag", "bio", "age", "dob", "sex", "pin",
-    "otp", "jwt", "ssh", "dns", "vpn", "api", "app", "env", "src",
-    "raw", "img", "svg", "pdf", "doc", "faq", "sku", "ean", "upc",
-    "vat", "tax", "fee", "qty", "sum", "avg", "min", "max", "ttl",
-    "lat", "lng", "lon", "alt", "zip", "geo", "map", "log", "job",
-    "pid", "rid", "tid", "eid", "gid", "cid", "sid", "mid",
-    "cpu", "ram", "gpu", "ssd", "hdd", "mac", "ip4", "ip6",
-    "org", "hub", "pod", "vpc", "cdn", "ssl", "tls", "arn", "iam",
-    "nft", "dao", "gas", "eth", "btc", "abi", "elo", "mmr",
-    "xp", "hp", "mp", "sp",
+    "id", "pk", "key", "uid", "me", "ok", "ip", "to", "cc", "by", "on", "at", "of", "in", "up",
+    "no", "do", "is", "or", "as", "ref", "url", "uri", "tag", "bio", "age", "dob", "sex", "pin",
+    "otp", "jwt", "ssh", "dns", "vpn", "api", "app", "env", "src", "raw", "img", "svg", "pdf",
+    "doc", "faq", "sku", "ean", "upc", "vat", "tax", "fee", "qty", "sum", "avg", "min", "max",
+    "ttl", "lat", "lng", "lon", "alt", "zip", "geo", "map", "log", "job", "pid", "rid", "tid",
+    "eid", "gid", "cid", "sid", "mid", "cpu", "ram", "gpu", "ssd", "hdd", "mac", "ip4", "ip6",
+    "org", "hub", "pod", "vpc", "cdn", "ssl", "tls", "arn", "iam", "nft", "dao", "gas", "eth",
+    "btc", "abi", "elo", "mmr", "xp", "hp", "mp", "sp",
 ];
 
+/// The conventional root type name for each operation kind, used until a
+/// suggestion error reveals the server's actual name for it.
+fn default_root_type_name(operation: &str) -> String {
+    match operation {
+        "mutation" => "Mutation".to_string(),
+        "subscription" => "Subscription".to_string(),
+        _ => "Query".to_string(),
+    }
+}
+
 /// Build context queries for reaching a type from a root field.
-/// Tries both bare field and field-with-id-arg patterns.
-fn build_root_context_queries(field_name: &str) -> Vec<String> {
-    vec![
-        field_name.to_string(),
-        format!("{}(id: \"1\")", field_name),
-    ]
+/// Tries the bare field plus, if we've already discovered the field's required
+/// argument (e.g. via required-argument probing), a call using its real name and
+/// an appropriately-quoted dummy value; falls back to the historical `(id: "1")`
+/// guess when nothing better is known yet.
+fn build_root_context_queries(
+    field_name: &str,
+    schema: &ReconstructedSchema,
+    root_type: &str,
+) -> Vec<String> {
+    let mut contexts = vec![field_name.to_string()];
+
+    let required_arg = schema
+        .types
+        .get(root_type)
+        .and_then(|t| t.fields.get(field_name))
+        .and_then(|f| f.arguments.values().find(|a| a.required));
+
+    match required_arg {
+        Some(arg) => {
+            let is_numeric = arg.type_name.as_deref().is_some_and(|t| {
+                t.trim_end_matches('!') == "Int" || t.trim_end_matches('!') == "Float"
+            });
+            let value = if is_numeric {
+                "1".to_string()
+            } else {
+                "\"1\"".to_string()
+            };
+            contexts.push(format!("{}({}: {})", field_name, arg.name, value));
+        }
+        None => contexts.push(format!("{}(id: \"1\")", field_name)),
+    }
+
+    contexts
 }
 
 /// Heuristic: check if a field name is likely a scalar (not an object type).
@@ -499,8 +1161,26 @@ mod tests {
     }
 
     #[test]
-    fn test_build_root_context_queries() {
-        let contexts = build_root_context_queries("user");
+    fn test_build_root_context_queries_defaults_to_id_guess() {
+        let schema = ReconstructedSchema::new();
+        let contexts = build_root_context_queries("user", &schema, "Query");
         assert_eq!(contexts, vec!["user", "user(id: \"1\")"]);
     }
+
+    #[test]
+    fn test_build_root_context_queries_uses_discovered_argument() {
+        let mut schema = ReconstructedSchema::new();
+        schema.add_field("Query", "order", DiscoveryMethod::SuggestionError);
+        schema.set_argument_info("Query", "order", "orderId", Some("Int!"), true);
+
+        let contexts = build_root_context_queries("order", &schema, "Query");
+        assert_eq!(contexts, vec!["order", "order(orderId: 1)"]);
+    }
+
+    #[test]
+    fn test_default_root_type_name() {
+        assert_eq!(default_root_type_name("query"), "Query");
+        assert_eq!(default_root_type_name("mutation"), "Mutation");
+        assert_eq!(default_root_type_name("subscription"), "Subscription");
+    }
 }
@@ -203,49 +203,121 @@ pub const BASE_WORDS: &[&str] = &[
     "viewer",
 ];
 
-/// Generate typo mutations of a word to maximize "Did you mean...?" hits.
-pub fn generate_mutations(word: &str) -> Vec<String> {
-    let mut mutations = Vec::new();
+/// The maximum optimal-string-alignment distance at which async-graphql /
+/// graphql-js will still emit a "Did you mean?" suggestion for a query of the
+/// given length: `floor(len * 0.4) + 1`, computed in integer arithmetic since
+/// `0.4 == 2/5` exactly.
+pub fn suggestion_threshold(probe_len: usize) -> usize {
+    probe_len * 2 / 5 + 1
+}
 
-    // Original word with a typo prefix -- almost guaranteed to not match
-    // but close enough to trigger suggestions
-    mutations.push(format!("x{}", word));
+/// Lowercase optimal-string-alignment distance (Levenshtein extended with a
+/// single adjacent-transposition rule, i.e. restricted Damerau), matching the
+/// algorithm graphql-js/async-graphql use to decide which candidates to
+/// suggest. Computed row by row; a row whose minimum already exceeds
+/// `max_distance` can't produce a final distance within it either, so it
+/// short-circuits rather than finishing the table -- this keeps scoring cheap
+/// when run over the full wordlist.
+pub fn osa_distance(a: &str, b: &str, max_distance: usize) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let (m, n) = (a.len(), b.len());
+    let unreachable = max_distance + 1;
 
-    // Drop last character
-    if word.len() > 2 {
-        mutations.push(word[..word.len() - 1].to_string());
+    if m == 0 {
+        return n;
+    }
+    if n == 0 {
+        return m;
     }
 
-    // Swap first two chars
-    if word.len() >= 2 {
-        let chars: Vec<char> = word.chars().collect();
-        let mut swapped = chars.clone();
-        swapped.swap(0, 1);
-        let s: String = swapped.into_iter().collect();
-        if s != word {
-            mutations.push(s);
+    let mut prev_prev: Vec<usize> = vec![0; n + 1];
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut curr: Vec<usize> = vec![0; n + 1];
+
+    for i in 1..=m {
+        curr[0] = i;
+        let mut row_min = curr[0];
+
+        for j in 1..=n {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut value = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                value = value.min(prev_prev[j - 2] + 1);
+            }
+
+            curr[j] = value;
+            row_min = row_min.min(value);
+        }
+
+        if row_min > max_distance {
+            return unreachable;
         }
+
+        prev_prev = std::mem::replace(&mut prev, std::mem::replace(&mut curr, vec![0; n + 1]));
+    }
+
+    prev[n]
+}
+
+/// Reorder suggestions the server already returned for `probe` by ascending
+/// edit distance (ties broken alphabetically), so the closest -- and most
+/// confident -- match comes first. The server only ever suggests candidates
+/// inside its own threshold, so this ranks rather than filters.
+pub fn rank_suggestions(probe: &str, suggestions: &[String]) -> Vec<String> {
+    let threshold = suggestion_threshold(probe.len());
+    let mut ranked: Vec<(usize, String)> = suggestions
+        .iter()
+        .map(|s| (osa_distance(probe, s, threshold.max(s.len())), s.clone()))
+        .collect();
+    ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+    ranked.into_iter().map(|(_, s)| s).collect()
+}
+
+/// Generate the minimal single-edit variants of `word` -- one deletion, one
+/// insertion, one substitution, one transposition -- that are provably inside
+/// the suggestion window (each sits at OSA distance 1, and `suggestion_threshold`
+/// is always >= 1), instead of the old grab-bag of suffix variants that mostly
+/// just burned probe budget without improving hit rate.
+pub fn generate_mutations(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut mutations = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    // Insertion: an unrelated leading character guaranteed not to collide
+    // with a real field, but only one edit away from it.
+    let inserted = format!("x{}", word);
+    if inserted != word && seen.insert(inserted.clone()) {
+        mutations.push(inserted);
     }
 
-    // Add common suffixes
-    mutations.push(format!("{}s", word));
-    mutations.push(format!("{}Id", word));
-    mutations.push(format!("{}By", word));
-    mutations.push(format!("{}List", word));
+    // Deletion: drop the last character.
+    if chars.len() > 1 {
+        let deleted: String = chars[..chars.len() - 1].iter().collect();
+        if deleted != word && seen.insert(deleted.clone()) {
+            mutations.push(deleted);
+        }
+    }
 
-    // Uppercase first letter variant
-    if let Some(first) = word.chars().next() {
-        if first.is_lowercase() {
-            let upper: String = first.to_uppercase().collect::<String>() + &word[first.len_utf8()..];
-            mutations.push(upper);
+    // Substitution: replace the last character with one that can't already be there.
+    if let Some(&last) = chars.last() {
+        let replacement = if last == 'z' { 'q' } else { 'z' };
+        let mut substituted = chars.clone();
+        *substituted.last_mut().unwrap() = replacement;
+        let substituted: String = substituted.into_iter().collect();
+        if substituted != word && seen.insert(substituted.clone()) {
+            mutations.push(substituted);
         }
     }
 
-    // Lowercase first letter variant
-    if let Some(first) = word.chars().next() {
-        if first.is_uppercase() {
-            let lower: String = first.to_lowercase().collect::<String>() + &word[first.len_utf8()..];
-            mutations.push(lower);
+    // Transposition: swap the first two characters.
+    if chars.len() >= 2 {
+        let mut swapped = chars.clone();
+        swapped.swap(0, 1);
+        let swapped: String = swapped.into_iter().collect();
+        if swapped != word && seen.insert(swapped.clone()) {
+            mutations.push(swapped);
         }
     }
 
@@ -268,3 +340,68 @@ pub fn full_probe_list() -> Vec<String> {
 
     probes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osa_distance_plain_levenshtein() {
+        assert_eq!(osa_distance("user", "user", 5), 0);
+        assert_eq!(osa_distance("xuser", "user", 5), 1);
+        assert_eq!(osa_distance("usr", "user", 5), 1);
+    }
+
+    #[test]
+    fn test_osa_distance_counts_transposition_as_one_edit() {
+        // "usre" -> "user" is a single adjacent swap, not two substitutions.
+        assert_eq!(osa_distance("usre", "user", 5), 1);
+    }
+
+    #[test]
+    fn test_osa_distance_is_case_insensitive() {
+        assert_eq!(osa_distance("User", "user", 5), 0);
+    }
+
+    #[test]
+    fn test_osa_distance_early_exit_reports_unreachable() {
+        assert_eq!(osa_distance("abcdef", "zzzzzz", 2), 3);
+    }
+
+    #[test]
+    fn test_suggestion_threshold() {
+        assert_eq!(suggestion_threshold(4), 2);
+        assert_eq!(suggestion_threshold(5), 3);
+        assert_eq!(suggestion_threshold(1), 1);
+    }
+
+    #[test]
+    fn test_rank_suggestions_orders_by_ascending_distance() {
+        let ranked = rank_suggestions(
+            "xname",
+            &["email".to_string(), "name".to_string(), "role".to_string()],
+        );
+        assert_eq!(ranked, vec!["name", "email", "role"]);
+    }
+
+    #[test]
+    fn test_generate_mutations_are_all_single_edit() {
+        for &word in &["user", "id", "a"] {
+            for mutation in generate_mutations(word) {
+                assert!(
+                    osa_distance(word, &mutation, 1) <= 1,
+                    "{} -> {} should be a single edit",
+                    word,
+                    mutation
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_mutations_deduplicates() {
+        let mutations = generate_mutations("id");
+        let unique: std::collections::HashSet<_> = mutations.iter().collect();
+        assert_eq!(mutations.len(), unique.len());
+    }
+}